@@ -0,0 +1,91 @@
+use shared::{Metadata, Solution};
+use std::cmp::Reverse;
+
+#[derive(Debug)]
+struct Elf {
+    calories: usize,
+}
+
+impl Elf {
+    fn new(calories: usize) -> Self {
+        Self { calories }
+    }
+}
+
+pub fn process_part1(content: &str) -> Option<usize> {
+    let elves = collect_elves(content);
+    let elf_for_sacks = looking_elf_for_snacks(&elves)?;
+    Some(elf_for_sacks.calories)
+}
+
+pub fn process_part2(content: &str) -> Option<usize> {
+    let mut elves = collect_elves(content);
+    elves.sort_by_key(|elf| Reverse(elf.calories));
+    let lead_elves = elves.get(..3)?;
+    Some(lead_elves.iter().map(|elf| elf.calories).sum())
+}
+
+fn collect_elves(content: &str) -> Vec<Elf> {
+    let mut elves = Vec::new();
+    let mut calories = Vec::new();
+    for line in content.lines() {
+        match line.parse::<usize>() {
+            Ok(calorie) => calories.push(calorie),
+            Err(_) => {
+                elves.push(Elf::new(calories.iter().sum()));
+                calories.clear()
+            }
+        };
+    }
+    if !calories.is_empty() {
+        elves.push(Elf::new(calories.iter().sum()));
+    }
+    elves
+}
+
+fn looking_elf_for_snacks(elves: &[Elf]) -> Option<&Elf> {
+    let mut elf_have_most_calories = elves.first();
+    for elf in elves {
+        match elf_have_most_calories {
+            Some(current_elf) => {
+                if elf.calories > current_elf.calories {
+                    elf_have_most_calories = Some(elf)
+                }
+            }
+            None => elf_have_most_calories = Some(elf),
+        }
+    }
+    elf_have_most_calories
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 1,
+        title: "Calorie Counting",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
+    }
+
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
+    }
+
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(collect_elves(content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day1);
+}
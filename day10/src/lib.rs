@@ -1,3 +1,6 @@
+use shared::{Metadata, Solution};
+use std::fmt::Display;
+
 enum Command {
     Addx(isize),
     Noop,
@@ -80,14 +83,14 @@ impl Cpu {
     }
 }
 
-pub fn process_part1(content: String) -> Option<isize> {
+pub fn process_part1(content: &str) -> Option<isize> {
     let commands = content.lines().map(|line| Command::parse(line)).collect();
     let mut cpu = Cpu::new(commands);
     let result = cpu.run_and_monitor_interesting_signal_strengths();
     Some(result)
 }
 
-pub fn process_part2(content: String) -> String {
+pub fn process_part2(content: &str) -> String {
     let commands = content.lines().map(|line| Command::parse(line)).collect();
     let mut cpu = Cpu::new(commands);
     let mut screen = Vec::new();
@@ -106,53 +109,52 @@ pub fn process_part2(content: String) -> String {
     screen.join("")
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 10;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Screen(String);
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(13140), answer);
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixels = self.0.chars().collect::<Vec<char>>();
+        let rows = pixels
+            .chunks(40)
+            .map(String::from_iter)
+            .collect::<Vec<String>>();
+        write!(f, "{}", rows.join("\n"))
     }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 10,
+        title: "Cathode-Ray Tube",
+    };
+    type Params = ();
+    type Answer1 = isize;
+    type Answer2 = Screen;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(14560), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        let expected = "\
-            ##..##..##..##..##..##..##..##..##..##..\
-            ###...###...###...###...###...###...###.\
-            ####....####....####....####....####....\
-            #####.....#####.....#####.....#####.....\
-            ######......######......######......####\
-            #######.......#######.......#######.....\
-        ";
-        assert_eq!(expected, answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        Some(Screen(process_part2(content)))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        let expected = "\
-            ####.#..#.###..#..#.####.###..#..#.####.\
-            #....#.#..#..#.#..#.#....#..#.#..#....#.\
-            ###..##...#..#.####.###..#..#.#..#...#..\
-            #....#.#..###..#..#.#....###..#..#..#...\
-            #....#.#..#.#..#..#.#....#....#..#.#....\
-            ####.#..#.#..#.#..#.####.#.....##..####.\
-        ";
-        assert_eq!(expected, answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(
+            content
+                .lines()
+                .map(Command::parse)
+                .collect::<Vec<Command>>(),
+        )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day10);
+}
@@ -1,5 +1,6 @@
 mod structs;
 
+use shared::{math::lcm_all, parse::parsed, Metadata, Solution};
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use structs::{parse_monkeys, Monkey};
 
@@ -7,9 +8,8 @@ type RMonkey = Rc<RefCell<Monkey>>;
 type RMonkeys = Vec<RMonkey>;
 
 fn simulate_rounds(monkeys: Vec<Monkey>, rounds: usize, very_worried: bool) -> RMonkeys {
-    let common_multiple_of_divisible_by = monkeys
-        .iter()
-        .fold(1, |result, monkey| result * monkey.get_test_divisible_by());
+    let common_multiple_of_divisible_by =
+        lcm_all(monkeys.iter().map(|monkey| monkey.get_test_divisible_by())).unwrap();
     let (mut monkeys, mut monkey_hash_map) = create_hashmap_with_refcell(monkeys);
     for _ in 0..rounds {
         for monkey in monkeys.iter_mut() {
@@ -30,7 +30,7 @@ fn simulate_rounds(monkeys: Vec<Monkey>, rounds: usize, very_worried: bool) -> R
             }
         }
     }
-    print_monkey_inspected_times(&monkeys);
+    shared::debug!("\n{}", render_inspected_times(&monkeys));
     monkeys
 }
 
@@ -46,14 +46,18 @@ fn create_hashmap_with_refcell(monkeys: Vec<Monkey>) -> (RMonkeys, HashMap<usize
     (new_monkeys, monkey_hash_map)
 }
 
-fn print_monkey_inspected_times(monkeys: &RMonkeys) {
-    for monkey in monkeys.iter() {
-        let monkey = monkey.borrow();
-        println!(
-            "Monkey {} inspected items {} times.",
-            monkey.id, monkey.inspected_times
-        );
-    }
+fn render_inspected_times(monkeys: &RMonkeys) -> String {
+    monkeys
+        .iter()
+        .map(|monkey| {
+            let monkey = monkey.borrow();
+            format!(
+                "Monkey {} inspected items {} times.",
+                monkey.id, monkey.inspected_times
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
 fn calculate_result_by_most_two_inspected_times_monkeys(mut monkeys: RMonkeys) -> usize {
@@ -65,51 +69,48 @@ fn calculate_result_by_most_two_inspected_times_monkeys(mut monkeys: RMonkeys) -
     result
 }
 
-pub fn process_part1(content: String) -> Option<usize> {
-    let monkeys = parse_monkeys(&content).unwrap();
+pub fn process_part1(content: &str) -> Option<usize> {
+    let monkeys = parsed(parse_monkeys(content));
     let monkeys = simulate_rounds(monkeys, 20, false);
     let result = calculate_result_by_most_two_inspected_times_monkeys(monkeys);
     Some(result)
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
-    let monkeys = parse_monkeys(&content).unwrap();
+pub fn process_part2(content: &str) -> Option<usize> {
+    let monkeys = parsed(parse_monkeys(content));
     let monkeys = simulate_rounds(monkeys, 10000, true);
     let result = calculate_result_by_most_two_inspected_times_monkeys(monkeys);
     Some(result)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 11;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day11;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(10605), answer);
-    }
+impl Solution for Day11 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 11,
+        title: "Monkey in the Middle",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(55944), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(2713310158), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(15117269860), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parsed(parse_monkeys(content)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day11);
+}
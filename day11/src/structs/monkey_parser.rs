@@ -2,25 +2,26 @@ use super::{Monkey, MonkeyTest, Operation, Value};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, digit1, newline, one_of, space0},
-    error::Error,
+    character::complete::{char, newline, one_of, space0},
+    combinator::map,
     multi::separated_list0,
     sequence::{delimited, preceded, tuple},
-    Err, IResult,
+    IResult,
 };
+use shared::parse::{parse_all, unsigned, ParseError};
 
 fn monkey_test_divisible_by_n(input: &str) -> IResult<&str, usize> {
     let (input, (_, divisible_by)) = tuple((
         tuple((tag("Test:"), space0, tag("divisible by"), space0)),
-        digit1,
+        unsigned,
     ))(input)?;
-    Ok((input, divisible_by.parse().unwrap()))
+    Ok((input, divisible_by))
 }
 
 fn throw_to_monkey_n(input: &str) -> IResult<&str, usize> {
     let (input, (_, monkey_number)) =
-        tuple((tuple((tag("throw to monkey"), space0)), digit1))(input)?;
-    Ok((input, monkey_number.parse().unwrap()))
+        tuple((tuple((tag("throw to monkey"), space0)), unsigned))(input)?;
+    Ok((input, monkey_number))
 }
 
 fn parse_monkey_test(input: &str) -> IResult<&str, MonkeyTest> {
@@ -34,26 +35,20 @@ fn parse_monkey_test(input: &str) -> IResult<&str, MonkeyTest> {
     Ok((input, MonkeyTest::new(divisible_by, if_true, if_false)))
 }
 
-fn value_or_number(input: &str) -> IResult<&str, &str> {
-    Ok(alt((tag("old"), digit1))(input)?)
-}
-
-fn parse_value(value_a: &str) -> Value {
-    if value_a == "old" {
-        return Value::Old;
-    }
-    Value::Number(value_a.parse().unwrap())
+fn parse_value(input: &str) -> IResult<&str, Value> {
+    alt((
+        map(tag("old"), |_| Value::Old),
+        map(unsigned, Value::Number),
+    ))(input)
 }
 
 fn parse_operation(input: &str) -> IResult<&str, Operation> {
     let (input, _) = tag("Operation: new = ")(input)?;
     let (input, (value_a, operation, value_b)) = tuple((
-        value_or_number,
+        parse_value,
         delimited(space0, one_of("+*"), space0),
-        value_or_number,
+        parse_value,
     ))(input)?;
-    let value_a = parse_value(value_a);
-    let value_b = parse_value(value_b);
     let operation = match operation {
         '*' => Operation::Multiple(value_a, value_b),
         '+' => Operation::Add(value_a, value_b),
@@ -64,26 +59,22 @@ fn parse_operation(input: &str) -> IResult<&str, Operation> {
 
 fn parse_items(input: &str) -> IResult<&str, Vec<usize>> {
     let (input, _) = tuple((tag("Starting items:"), space0))(input)?;
-    let (input, items) = separated_list0(tuple((space0, char(','), space0)), digit1)(input)?;
-    let items = items.iter().map(|item| item.parse().unwrap()).collect();
-    Ok((input, items))
+    separated_list0(tuple((space0, char(','), space0)), unsigned)(input)
 }
 
 fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
-    let monkey_line = delimited(tag("Monkey "), digit1, char(':'));
+    let monkey_line = delimited(tag("Monkey "), unsigned, char(':'));
     let (input, (id, items, operation, monkey_test)) = tuple((
         delimited(space0, monkey_line, newline),
         delimited(space0, parse_items, newline),
         delimited(space0, parse_operation, newline),
         parse_monkey_test,
     ))(input)?;
-    let id = id.parse().unwrap();
     Ok((input, Monkey::new(id, items, operation, monkey_test)))
 }
 
-pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, Err<Error<&str>>> {
-    let (_, monkeys) = separated_list0(newline, parse_monkey)(input)?;
-    Ok(monkeys)
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    parse_all(input, separated_list0(newline, parse_monkey))
 }
 
 #[cfg(test)]
@@ -98,7 +89,6 @@ mod tests {
     If true: throw to monkey 2
     If false: throw to monkey 3
 ";
-        println!("{}", input);
         let result = parse_monkey_test(input).unwrap();
         let monkey_test = result.1;
         assert!(matches!(
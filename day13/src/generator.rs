@@ -0,0 +1,69 @@
+use crate::structs::Element;
+use shared::random::Rng;
+use std::cmp::Ordering;
+
+const MAX_DEPTH: usize = 4;
+
+fn random_element(rng: &mut Rng, depth: usize) -> Element {
+    if depth < MAX_DEPTH && rng.chance(30) {
+        random_list(rng, depth + 1)
+    } else {
+        Element::Atom(rng.usize_in(0..=10))
+    }
+}
+
+fn random_list(rng: &mut Rng, depth: usize) -> Element {
+    let length = rng.usize_in(0..=5);
+    Element::List((0..length).map(|_| random_element(rng, depth)).collect())
+}
+
+/// One small change somewhere in `element`, so the pairs share a prefix like the puzzle.
+fn mutate(element: &mut Element, rng: &mut Rng, depth: usize) {
+    match element {
+        Element::Atom(value) if rng.chance(20) => {
+            let extra = Element::Atom(rng.usize_in(0..=10));
+            *element = Element::List(vec![Element::Atom(*value), extra]);
+        }
+        Element::Atom(value) => *value = rng.usize_in(0..=10),
+        Element::List(children) if children.is_empty() || rng.chance(30) => {
+            if children.is_empty() || rng.chance(50) {
+                children.push(random_element(rng, depth));
+            } else {
+                children.pop();
+            }
+        }
+        Element::List(children) => {
+            let index = rng.below(children.len());
+            mutate(&mut children[index], rng, depth + 1);
+        }
+    }
+}
+
+/// `size` pairs of packets. No two of a pair are in an undecided order, and no packet is
+/// in one with the divider packets.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let dividers = [
+        Element::List(vec![Element::List(vec![Element::Atom(2)])]),
+        Element::List(vec![Element::List(vec![Element::Atom(6)])]),
+    ];
+    let is_decided = |left: &Element, right: &Element| left.cmp(right) != Ordering::Equal;
+    let mut pairs = Vec::new();
+    while pairs.len() < size {
+        let left = random_list(rng, 1);
+        let mut right = left.clone();
+        mutate(&mut right, rng, 1);
+        let (left, right) = if rng.chance(50) {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        let is_valid = is_decided(&left, &right)
+            && dividers
+                .iter()
+                .all(|divider| is_decided(&left, divider) && is_decided(&right, divider));
+        if is_valid {
+            pairs.push(format!("{}\n{}\n", left, right));
+        }
+    }
+    pairs.join("\n")
+}
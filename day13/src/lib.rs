@@ -1,16 +1,13 @@
+mod generator;
 mod parser;
 mod structs;
 
-use parser::parse;
+use parser::{parse_packet, parse_pairs};
+use shared::{parse::parsed, random::Rng, Metadata, Solution};
 use structs::Element;
 
-pub fn process_part1(content: String) -> Option<usize> {
-    let mut pairs = Vec::new();
-    content
-        .lines()
-        .collect::<Vec<&str>>()
-        .split(|line| *line == "")
-        .for_each(|e| pairs.push((parse(e[0]).unwrap(), parse(e[1]).unwrap())));
+pub fn process_part1(content: &str) -> Option<usize> {
+    let pairs = parsed(parse_pairs(content));
     let mut sum = 0;
     for (index, (left, right)) in pairs.into_iter().enumerate() {
         let pair_number = index + 1;
@@ -22,11 +19,11 @@ pub fn process_part1(content: String) -> Option<usize> {
     Some(sum)
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
-    let divider_packets = [parse("[[2]]").unwrap(), parse("[[6]]").unwrap()];
-    let mut packets = content
-        .lines()
-        .filter_map(|line| if line == "" { None } else { parse(line) })
+pub fn process_part2(content: &str) -> Option<usize> {
+    let divider_packets = [parsed(parse_packet("[[2]]")), parsed(parse_packet("[[6]]"))];
+    let mut packets = parsed(parse_pairs(content))
+        .into_iter()
+        .flat_map(|(left, right)| [left, right])
         .collect::<Vec<Element>>();
     packets.extend_from_slice(&divider_packets);
     packets.sort();
@@ -40,37 +37,38 @@ pub fn process_part2(content: String) -> Option<usize> {
     Some(decoder_key)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 13;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day13;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(13), answer);
+impl Solution for Day13 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 13,
+        title: "Distress Signal",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(5623), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(140), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parsed(parse_pairs(content)))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(20570), answer);
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day13);
+}
@@ -2,15 +2,17 @@ use crate::structs::Element;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, space0},
+    character::complete::{line_ending, space0},
+    combinator::cut,
     multi::separated_list0,
-    sequence::{delimited, preceded},
+    sequence::{delimited, preceded, separated_pair},
     IResult,
 };
+use shared::parse::{blocks, parse_all, unsigned, ParseError};
 
 fn atom(input: &str) -> IResult<&str, Element> {
-    let (input, number) = preceded(space0, digit1)(input)?;
-    Ok((input, Element::Atom(number.parse().unwrap())))
+    let (input, number) = preceded(space0, unsigned)(input)?;
+    Ok((input, Element::Atom(number)))
 }
 
 fn list(input: &str) -> IResult<&str, Element> {
@@ -27,9 +29,15 @@ fn atom_or_list(input: &str) -> IResult<&str, Element> {
     Ok((input, element))
 }
 
-pub fn parse(input: &str) -> Option<Element> {
-    let (_, element) = atom_or_list(input).ok()?;
-    Some(element)
+/// A single packet, which is always a list.
+pub fn parse_packet(input: &str) -> Result<Element, ParseError> {
+    parse_all(input, list)
+}
+
+/// The pairs of packets, each on two lines with a blank line between the pairs. Once the
+/// first packet of a pair is read the second one has to follow, so an error points at it.
+pub fn parse_pairs(input: &str) -> Result<Vec<(Element, Element)>, ParseError> {
+    parse_all(input, blocks(separated_pair(list, line_ending, cut(list))))
 }
 
 #[cfg(test)]
@@ -40,7 +48,6 @@ mod tests {
     fn test_atom_or_list() {
         let sample = "[1,[2,[3,[4,[5,6,7]]]],8,9]";
         let (_, element) = list(sample).unwrap();
-        dbg!(&element);
         let expect = Element::List(vec![
             Element::Atom(1),
             Element::List(vec![
@@ -58,4 +65,10 @@ mod tests {
         ]);
         assert_eq!(expect, element)
     }
+
+    #[test]
+    fn test_parse_pairs_error() {
+        let err = parse_pairs("[1]\n[2]\n\n[3]\n[4,]\n").unwrap_err();
+        assert_eq!((5, 3), (err.line, err.column));
+    }
 }
@@ -77,19 +77,19 @@ fn compare_list_to_list(left: &Vec<Element>, right: &Vec<Element>) -> Ordering {
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::parse;
+    use crate::parser::parse_packet;
 
     #[test]
     fn test_ord_empty_arrays() {
-        let left = parse("[1]").unwrap();
-        let right = parse("[[]]").unwrap();
+        let left = parse_packet("[1]").unwrap();
+        let right = parse_packet("[[]]").unwrap();
         assert!(left.gt(&right))
     }
 
     #[test]
     fn test_ord_atoms() {
-        let left = parse("[1,1,3,1,1]").unwrap();
-        let right = parse("[1,1,5,1,1]").unwrap();
+        let left = parse_packet("[1,1,3,1,1]").unwrap();
+        let right = parse_packet("[1,1,5,1,1]").unwrap();
         assert!(left.lt(&right))
     }
 }
@@ -2,6 +2,7 @@ mod parser;
 mod structs;
 
 use parser::parse;
+use shared::{Metadata, Solution};
 use structs::BigMap;
 
 fn simulate_sand_drop(mut big_map: BigMap) -> usize {
@@ -16,51 +17,48 @@ fn simulate_sand_drop(mut big_map: BigMap) -> usize {
     sand_count
 }
 
-pub fn process_part1(content: String) -> Option<usize> {
-    let rocks = content.lines().map(parse).collect();
+pub fn process_part1(content: &str) -> Option<usize> {
+    let rocks = parse(content);
     let big_map = BigMap::new(rocks);
     let sand_count = simulate_sand_drop(big_map);
     Some(sand_count)
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
-    let rocks = content.lines().map(parse).collect();
+pub fn process_part2(content: &str) -> Option<usize> {
+    let rocks = parse(content);
     let big_map = BigMap::new_with_floor(rocks);
     let sand_count = simulate_sand_drop(big_map);
     Some(sand_count)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 14;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day14;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(24), answer);
-    }
+impl Solution for Day14 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 14,
+        title: "Regolith Reservoir",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(1061), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(93), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(25055), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse(content))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day14);
+}
@@ -1,39 +1,20 @@
 use crate::structs::{Point, Record};
-use nom::{
-    bytes::complete::tag,
-    character::complete::{char, digit1},
-    combinator::opt,
-    sequence::{pair, preceded},
-    IResult,
-};
-
-fn number(input: &str) -> IResult<&str, isize> {
-    let (input, (sign, number)) = pair(opt(char('-')), digit1)(input)?;
-    let mut number = number.parse().unwrap();
-    if sign.is_some() {
-        number *= -1;
-    }
-    Ok((input, number))
-}
+use nom::{bytes::complete::tag, sequence::preceded, IResult};
+use shared::parse::{parse_lines, parsed, signed};
 
 fn parse_line(input: &str) -> IResult<&str, [isize; 4]> {
-    let (input, s_x) = preceded(tag("Sensor at x="), number)(input)?;
-    let (input, s_y) = preceded(tag(", y="), number)(input)?;
-    let (input, b_x) = preceded(tag(": closest beacon is at x="), number)(input)?;
-    let (input, b_y) = preceded(tag(", y="), number)(input)?;
+    let (input, s_x) = preceded(tag("Sensor at x="), signed)(input)?;
+    let (input, s_y) = preceded(tag(", y="), signed)(input)?;
+    let (input, b_x) = preceded(tag(": closest beacon is at x="), signed)(input)?;
+    let (input, b_y) = preceded(tag(", y="), signed)(input)?;
     Ok((input, [s_x, s_y, b_x, b_y]))
 }
 
 pub fn parse(input: &str) -> Vec<Record> {
-    let mut records = Vec::new();
-    for line in input.lines() {
-        let (_, data) = parse_line(line).unwrap();
-        records.push(Record::new(
-            Point::new(data[0], data[1]),
-            Point::new(data[2], data[3]),
-        ));
-    }
-    records
+    parsed(parse_lines(input, parse_line))
+        .into_iter()
+        .map(|data| Record::new(Point::new(data[0], data[1]), Point::new(data[2], data[3])))
+        .collect()
 }
 
 #[cfg(test)]
@@ -46,4 +27,11 @@ mod test {
         let (_, data) = parse_line(input).unwrap();
         assert_eq!([2, 18, -2, 15], data)
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=2, y=18:";
+        let err = parse_lines(input, parse_line).unwrap_err();
+        assert_eq!((2, 20), (err.line, err.column));
+    }
 }
@@ -12,6 +12,33 @@ impl Point {
     }
 }
 
+/// A diagonal line of the map, the points with `x + y` or with `x - y` of its number.
+#[derive(Clone, Copy, Debug)]
+pub enum Diagonal {
+    Sum(isize),
+    Difference(isize),
+}
+
+impl Diagonal {
+    /// The `x` of its points within `area` on both axes.
+    pub fn xs_within(&self, area: &RangeInclusive<isize>) -> RangeInclusive<isize> {
+        let (low, high) = (*area.start(), *area.end());
+        match *self {
+            Diagonal::Sum(sum) => low.max(sum - high)..=high.min(sum - low),
+            Diagonal::Difference(difference) => {
+                low.max(low + difference)..=high.min(high + difference)
+            }
+        }
+    }
+
+    pub fn point_at(&self, x: isize) -> Point {
+        match *self {
+            Diagonal::Sum(sum) => Point::new(x, sum - x),
+            Diagonal::Difference(difference) => Point::new(x, x - difference),
+        }
+    }
+}
+
 pub struct Record {
     sensor: Point,
     beacon: Point,
@@ -41,6 +68,36 @@ impl Record {
         (center - distance)..=(center + distance)
     }
 
+    /// The four diagonals right outside the edges of what it covers.
+    pub fn outer_diagonals(&self) -> [Diagonal; 4] {
+        let reach = self.covered_distance() as isize + 1;
+        let (sum, difference) = (self.sensor.x + self.sensor.y, self.sensor.x - self.sensor.y);
+        [
+            Diagonal::Sum(sum - reach),
+            Diagonal::Sum(sum + reach),
+            Diagonal::Difference(difference - reach),
+            Diagonal::Difference(difference + reach),
+        ]
+    }
+
+    /// The `x` of the points of `diagonal` it covers. Turned by 45 degrees what it covers
+    /// is a square, so `diagonal` either crosses it from edge to edge or misses it.
+    pub fn covered_xs_on(&self, diagonal: Diagonal) -> Option<RangeInclusive<isize>> {
+        let distance = self.covered_distance() as isize;
+        let (sum, difference) = (self.sensor.x + self.sensor.y, self.sensor.x - self.sensor.y);
+        // `x` is half of the sum of the two, which are both within `distance` of the center.
+        let (line, along, center) = match diagonal {
+            Diagonal::Sum(line) => (line, sum, difference),
+            Diagonal::Difference(line) => (line, difference, sum),
+        };
+        if line.abs_diff(along) > distance as usize {
+            return None;
+        }
+        let start = (center - distance + line + 1).div_euclid(2);
+        let end = (center + distance + line).div_euclid(2);
+        Some(start..=end)
+    }
+
     pub fn get_beacon(&self) -> &Point {
         &self.beacon
     }
@@ -50,58 +107,35 @@ impl Record {
     }
 }
 
-#[derive(Clone, Debug)]
-enum Mark {
-    Empty,
-    Sensor,
-    Beacon,
-    Coverd,
-}
-
-pub struct Boundary {
-    left: isize,
-    right: isize,
-    marks: Vec<Mark>,
-}
-
-impl Boundary {
-    pub fn new(left: isize, right: isize) -> Self {
-        let size = right.abs_diff(left);
-        let marks = vec![Mark::Empty; size + 1];
-        Boundary { left, right, marks }
-    }
-
-    pub fn count_coverd(&self) -> usize {
-        self.marks
-            .iter()
-            .filter(|mark| matches!(mark, Mark::Coverd))
-            .count()
-    }
-
-    pub fn set_beacon(&mut self, position: isize) {
-        self.set_mark(position, Mark::Beacon);
-    }
-
-    pub fn set_sensor(&mut self, position: isize) {
-        self.set_mark(position, Mark::Sensor);
-    }
-
-    pub fn set_coverd(&mut self, position: isize) {
-        self.set_mark(position, Mark::Coverd);
-    }
-
-    fn set_mark(&mut self, position: isize, mark: Mark) {
-        let real_positon = self.real_positon(position);
-        if let Mark::Empty = self.marks[real_positon] {
-            self.marks[real_positon] = mark
-        }
-    }
-
-    fn real_positon(&self, position: isize) -> usize {
-        if !(self.left..=self.right).contains(&position) {
-            panic!("out of range! {}..{}, {}", self.left, self.right, position)
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_covered_xs_on() {
+        let record = Record::new(Point::new(3, -2), Point::new(5, -1));
+        let area = -10..=10;
+        for line in -12..=12 {
+            for diagonal in [Diagonal::Sum(line), Diagonal::Difference(line)] {
+                let expected = diagonal
+                    .xs_within(&area)
+                    .filter(|&x| {
+                        let point = diagonal.point_at(x);
+                        record.sensor.x.abs_diff(point.x) + record.sensor.y.abs_diff(point.y) <= 3
+                    })
+                    .collect::<Vec<_>>();
+                let covered = record
+                    .covered_xs_on(diagonal)
+                    .map(|xs| xs.collect::<Vec<_>>())
+                    .unwrap_or_default();
+                assert_eq!(expected, covered, "{:?}", diagonal);
+            }
         }
-        let real_positon = (position - self.left) as usize;
-        real_positon
+        assert_eq!(
+            [-3, 5, 1, 9],
+            record.outer_diagonals().map(|diagonal| match diagonal {
+                Diagonal::Sum(line) | Diagonal::Difference(line) => line,
+            })
+        );
     }
 }
@@ -0,0 +1,68 @@
+use shared::random::Rng;
+
+/// Two letters make 676 names.
+const MAX_VALVES: usize = 26 * 26;
+
+/// `size` valves with a flow rate in a connected network of about three times as many,
+/// starting from a broken `AA` like the puzzle. Part 2 gets slow fast, it tries every way
+/// to split the working valves.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let working_count = size.clamp(1, MAX_VALVES / 3);
+    let valve_count = working_count * 3 + 1;
+    let mut names = (0..MAX_VALVES)
+        .map(|index| {
+            let letters = [b'A' + (index / 26) as u8, b'A' + (index % 26) as u8];
+            String::from_utf8(letters.to_vec()).unwrap()
+        })
+        .skip(1)
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut names);
+    names.truncate(valve_count - 1);
+    names.insert(0, String::from("AA"));
+    let flow_rates = (0..valve_count)
+        .map(|index| {
+            if index > 0 && index <= working_count {
+                rng.usize_in(1..=25)
+            } else {
+                0
+            }
+        })
+        .collect::<Vec<usize>>();
+    let mut tunnels = vec![Vec::new(); valve_count];
+    let mut connect = |from: usize, to: usize| {
+        if from != to && !tunnels[from].contains(&to) {
+            tunnels[from].push(to);
+            tunnels[to].push(from);
+        }
+    };
+    // A random tree over a shuffled order keeps every valve reachable.
+    let mut order = (0..valve_count).collect::<Vec<usize>>();
+    rng.shuffle(&mut order);
+    for index in 1..valve_count {
+        connect(order[index], order[rng.below(index)]);
+    }
+    for _ in 0..valve_count / 4 {
+        connect(rng.below(valve_count), rng.below(valve_count));
+    }
+    let mut lines = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        let others = tunnels[index]
+            .iter()
+            .map(|&other| names[other].as_str())
+            .collect::<Vec<&str>>();
+        let lead = if others.len() == 1 {
+            "tunnel leads to valve"
+        } else {
+            "tunnels lead to valves"
+        };
+        lines.push(format!(
+            "Valve {} has flow rate={}; {} {}",
+            name,
+            flow_rates[index],
+            lead,
+            others.join(", ")
+        ));
+    }
+    rng.shuffle(&mut lines[1..]);
+    lines.join("\n") + "\n"
+}
@@ -1,17 +1,18 @@
+mod generator;
 mod parser;
 mod simulator;
 mod structs;
 
 use itertools::Itertools;
-use shared::log;
+use shared::{progress::Progress, random::Rng, Metadata, Solution};
 use simulator::{
     calculate_total_released_pressure, convert_plan_to_paths, create_valve_hash_map, get_cost,
     get_dogs,
 };
-use std::{collections::HashMap, rc::Rc};
+use std::collections::HashMap;
 use structs::{Dog, Valve};
 
-pub fn process_part1(content: String) -> Option<usize> {
+pub fn process_part1(content: &str) -> Option<usize> {
     let time = 30;
     let valves_map = create_valve_hash_map(content);
     let dogs = get_dogs(&valves_map);
@@ -42,7 +43,7 @@ fn idk_maybe_use_the_dfs_to_plan_the_order_then_open_valves(
     size: usize,
     time: usize,
     closed_valves: Vec<&str>,
-    dogs: &HashMap<&str, Rc<Dog>>,
+    dogs: &HashMap<&str, Dog>,
     valves_map: &HashMap<String, Valve>,
 ) -> usize {
     let mut result = 0;
@@ -79,11 +80,11 @@ fn idk_maybe_use_the_dfs_to_plan_the_order_then_open_valves(
     result
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
     let time = 26;
-    let valves_map_a = create_valve_hash_map(content.clone());
-    let valves_map_b = create_valve_hash_map(content.clone());
-    let valves_map_c = create_valve_hash_map(content.clone());
+    let valves_map_a = create_valve_hash_map(content);
+    let valves_map_b = create_valve_hash_map(content);
+    let valves_map_c = create_valve_hash_map(content);
     let mut closed_valves = valves_map_c
         .values()
         .filter_map(|valve| {
@@ -95,9 +96,13 @@ pub fn process_part2(content: String) -> Option<usize> {
         .collect::<Vec<_>>();
     closed_valves.sort();
     let size = closed_valves.len();
+    let total = (0..(size / 2 + 1))
+        .map(|split_index| binomial(size, split_index))
+        .sum();
+    let mut progress = Progress::new("day16 part 2", total);
     let mut result = 0;
     for split_index in 0..(size / 2 + 1) {
-        log(format!("{}", split_index).as_str());
+        shared::debug!("I open {} of {} valves", split_index, size);
         for my_valves in closed_valves.clone().into_iter().combinations(split_index) {
             let mut elephant_s_valvs = Vec::new();
             for valve in closed_valves.clone() {
@@ -126,42 +131,53 @@ pub fn process_part2(content: String) -> Option<usize> {
             if total_released_pressure > result {
                 result = total_released_pressure;
             }
+            if let Err(cancelled) = progress.inc(1) {
+                shared::warn!("{}", cancelled);
+                return None;
+            }
         }
     }
+    progress.finish();
     Some(result)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 16;
-    use super::*;
-    use shared::{read_input, read_sample};
+/// Number of ways to pick `k` valves out of `n`.
+fn binomial(n: usize, k: usize) -> u64 {
+    (0..k).fold(1, |ways, i| ways * (n - i) as u64 / (i + 1) as u64)
+}
+
+pub struct Day16;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(1651), answer);
+impl Solution for Day16 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 16,
+        title: "Proboscidea Volcanium",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(1923), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(1707), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(create_valve_hash_map(content))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(2594), answer);
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day16);
+}
@@ -1,11 +1,13 @@
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, one_of},
+    character::complete::one_of,
+    combinator::map,
     multi::{count, separated_list1},
     sequence::preceded,
     IResult,
 };
+use shared::parse::{parse_lines, parsed, unsigned};
 
 use crate::structs::Valve;
 
@@ -17,8 +19,7 @@ fn valve_name(input: &str) -> IResult<&str, String> {
 
 fn parse_line(input: &str) -> IResult<&str, (String, usize, Vec<String>)> {
     let (input, name) = preceded(tag("Valve "), valve_name)(input)?;
-    let (input, flow_rate) = preceded(tag(" has flow rate="), digit1)(input)?;
-    let flow_rate = flow_rate.parse().unwrap();
+    let (input, flow_rate) = preceded(tag(" has flow rate="), unsigned)(input)?;
     let (input, tunnels) = preceded(
         alt((
             tag("; tunnels lead to valves "),
@@ -29,9 +30,11 @@ fn parse_line(input: &str) -> IResult<&str, (String, usize, Vec<String>)> {
     Ok((input, (name, flow_rate, tunnels)))
 }
 
-pub fn parse(input: &str) -> Valve {
-    let (_, (name, flow_rate, tunnels)) = parse_line(input).unwrap();
-    Valve::new(name, flow_rate, tunnels)
+pub fn parse(input: &str) -> Vec<Valve> {
+    let valve = map(parse_line, |(name, flow_rate, tunnels)| {
+        Valve::new(name, flow_rate, tunnels)
+    });
+    parsed(parse_lines(input, valve))
 }
 
 #[cfg(test)]
@@ -3,19 +3,19 @@ use crate::{
     structs::{Dog, Path, Valve},
 };
 use lazy_static::lazy_static;
-use std::{collections::HashMap, rc::Rc, sync::Mutex};
+use shared::search::{bfs, Successors};
+use std::{collections::HashMap, sync::Mutex};
 
-pub fn create_valve_hash_map(content: String) -> HashMap<String, Valve> {
+pub fn create_valve_hash_map(content: &str) -> HashMap<String, Valve> {
     let mut valves = HashMap::new();
-    content.lines().for_each(|line| {
-        let valve = parse(line);
+    parse(content).into_iter().for_each(|valve| {
         let name = valve.get_name().to_string();
         valves.insert(name, valve);
     });
     valves
 }
 
-pub fn convert_plan_to_paths(plan: Vec<&str>, dogs: &HashMap<&str, Rc<Dog>>) -> Vec<Path> {
+pub fn convert_plan_to_paths(plan: Vec<&str>, dogs: &HashMap<&str, Dog>) -> Vec<Path> {
     let mut paths = Vec::new();
     let mut from = "AA";
     for to in plan {
@@ -30,7 +30,7 @@ lazy_static! {
     static ref HASHMAP: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
 }
 
-pub fn get_cost(from_name: &str, to_name: &str, dogs: &HashMap<&str, Rc<Dog>>) -> usize {
+pub fn get_cost(from_name: &str, to_name: &str, dogs: &HashMap<&str, Dog>) -> usize {
     let mut cache = HASHMAP.lock().unwrap();
     let mut cache_key = vec![from_name, to_name];
     cache_key.sort();
@@ -38,54 +38,38 @@ pub fn get_cost(from_name: &str, to_name: &str, dogs: &HashMap<&str, Rc<Dog>>) -
     if cache.contains_key(&cache_key) {
         return *cache.get(&cache_key).unwrap();
     };
-    dogs.values().for_each(|dog| dog.reset());
-    let from = dogs.get(from_name).unwrap().clone();
-    let to = dogs.get(to_name).unwrap().clone();
-    let mut history = Vec::new();
-    from.set_distance(0);
-    history.push(from);
-    loop {
-        history.sort_by(|a, b| b.get_distance().cmp(&a.get_distance()));
-        let current = history.pop();
-        if current.is_none() {
-            return usize::MAX;
-        }
-        let current = current.unwrap();
-        let neighbors = current.get_friends();
-        for neighbor in neighbors {
-            if neighbor.is_visited() {
-                continue;
-            }
-            let new_distance = current.get_distance() + 1;
-            if new_distance < neighbor.get_distance() {
-                neighbor.set_distance(new_distance);
-                history.push(neighbor);
-            }
-        }
-        current.set_visited();
-        if to.is_visited() {
-            break;
-        }
-    }
-    let distance = to.get_distance();
+    let tunnels = Tunnels { dogs };
+    let distance = bfs(&tunnels, from_name, |name| *name == to_name)
+        .map(|path| path.cost)
+        .unwrap_or(usize::MAX);
     cache.insert(cache_key, distance);
     distance
 }
 
-pub fn get_dogs(valves_map: &HashMap<String, Valve>) -> HashMap<&str, Rc<Dog>> {
-    let mut dogs = HashMap::new();
-    valves_map.values().for_each(|v| {
-        dogs.insert(v.get_name().clone(), Dog::new());
-    });
-    dogs.clone().keys().for_each(|k| {
-        let dog = dogs.get(k.clone()).unwrap();
-        let s = valves_map.get(k.clone()).unwrap();
-        s.get_tunnels().iter().for_each(|n| {
-            let friend = dogs.get(n.as_str().clone()).unwrap();
-            dog.push(friend.clone());
-        });
-    });
-    dogs
+struct Tunnels<'a, 'b> {
+    dogs: &'b HashMap<&'a str, Dog<'a>>,
+}
+
+impl<'a> Successors for Tunnels<'a, '_> {
+    type State = &'a str;
+
+    fn successors(&self, name: &&'a str) -> Vec<(&'a str, usize)> {
+        let dog = self.dogs.get(name).unwrap();
+        dog.get_friends()
+            .iter()
+            .map(|friend| (*friend, 1))
+            .collect()
+    }
+}
+
+pub fn get_dogs(valves_map: &HashMap<String, Valve>) -> HashMap<&str, Dog<'_>> {
+    valves_map
+        .values()
+        .map(|valve| {
+            let friends = valve.get_tunnels().iter().map(String::as_str).collect();
+            (valve.get_name(), Dog::new(friends))
+        })
+        .collect()
 }
 
 pub fn calculate_total_released_pressure(
@@ -112,21 +96,22 @@ pub fn calculate_total_released_pressure(
 mod tests {
     const DAY_NUMBER: usize = 16;
     use super::*;
-    use shared::{read_input, read_sample};
+    use shared::read_sample;
 
     #[test]
     fn test_get_cost() {
         let content = read_sample(DAY_NUMBER);
-        let valves_map = create_valve_hash_map(content);
+        let valves_map = create_valve_hash_map(&content);
         let dogs = get_dogs(&valves_map);
         assert_eq!(2, get_cost("EE", "AA", &dogs));
         assert_eq!(2, get_cost("AA", "EE", &dogs));
     }
 
     #[test]
+    #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
     fn test_get_cost_2() {
-        let content = read_input(DAY_NUMBER);
-        let valves_map = create_valve_hash_map(content);
+        let content = shared::read_input(DAY_NUMBER);
+        let valves_map = create_valve_hash_map(&content);
         let dogs = get_dogs(&valves_map);
         assert_eq!(5, get_cost("AA", "PH", &dogs));
         assert_eq!(5, get_cost("PH", "AA", &dogs));
@@ -135,7 +120,7 @@ mod tests {
     #[test]
     fn test_calculate_total_released_pressure() {
         let content = read_sample(DAY_NUMBER);
-        let valves_map = create_valve_hash_map(content);
+        let valves_map = create_valve_hash_map(&content);
         let plan = vec![
             Path::new(String::from("DD"), 1),
             Path::new(String::from("BB"), 2),
@@ -1,5 +1,3 @@
-use std::{cell::RefCell, rc::Rc};
-
 #[derive(Clone, Debug)]
 pub struct Valve {
     name: String,
@@ -30,48 +28,17 @@ impl Valve {
 }
 
 // Dijkstra of Grim
-pub struct Dog {
-    visited: RefCell<bool>,
-    distance: RefCell<usize>,
-    friends: RefCell<Vec<Rc<Dog>>>,
+pub struct Dog<'a> {
+    friends: Vec<&'a str>,
 }
 
-impl Dog {
-    pub fn new() -> Rc<Dog> {
-        Rc::new(Self {
-            visited: RefCell::new(false),
-            distance: RefCell::new(usize::MAX),
-            friends: RefCell::new(Vec::new()),
-        })
-    }
-
-    pub fn push(&self, friend: Rc<Dog>) {
-        self.friends.borrow_mut().push(friend)
-    }
-
-    pub fn set_distance(&self, distance: usize) {
-        *self.distance.borrow_mut() = distance
-    }
-
-    pub fn get_distance(&self) -> usize {
-        *self.distance.borrow()
-    }
-
-    pub fn get_friends(&self) -> Vec<Rc<Dog>> {
-        self.friends.borrow().clone()
-    }
-
-    pub fn is_visited(&self) -> bool {
-        *self.visited.borrow()
-    }
-
-    pub fn set_visited(&self) {
-        *self.visited.borrow_mut() = true
+impl<'a> Dog<'a> {
+    pub fn new(friends: Vec<&'a str>) -> Self {
+        Self { friends }
     }
 
-    pub fn reset(&self) {
-        *self.visited.borrow_mut() = false;
-        *self.distance.borrow_mut() = usize::MAX
+    pub fn get_friends(&self) -> &Vec<&'a str> {
+        &self.friends
     }
 }
 
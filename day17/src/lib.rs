@@ -1,3 +1,8 @@
+use shared::{
+    cycle::{Cycle, Detector},
+    Metadata, Solution,
+};
+
 enum Jet {
     L,
     R,
@@ -152,6 +157,12 @@ impl Hall {
         self.heighs.iter().max().unwrap() + self.ignored_heigh
     }
 
+    /// How deep each column is below the top.
+    fn skyline(&self) -> [usize; 7] {
+        let top = self.heighs.iter().max().unwrap();
+        self.heighs.map(|heigh| top - heigh)
+    }
+
     fn can_fit(&self, points: &Vec<Point>) -> bool {
         !points.iter().any(|point| self.is_blocked(&point))
     }
@@ -264,28 +275,35 @@ impl Rock {
     }
 }
 
-fn simulate_n_rocks(content: String, amount_of_rocks: usize) -> Hall {
-    let mut rock_types = Generator::new(vec![
-        RockType::Bar,
-        RockType::Star,
-        RockType::Crowbar,
-        RockType::Stick,
-        RockType::Block,
-    ]);
-    let mut jets = Generator::new(parse_jets(&content));
-    let sad = (rock_types.size + 1) * (jets.size + 1) * 345;
-    dbg!(sad, rock_types.size, jets.size);
-    let mut hall = Hall::new();
-    #[cfg(any(finding_repeat = "sample", finding_repeat = "input"))]
-    let mut last_top = 0;
-    for _i in 0..amount_of_rocks {
-        let rock_type = rock_types.next();
-        let point = Point::new(2, hall.top() as isize + 3);
+struct Chamber {
+    rock_types: Generator<RockType>,
+    jets: Generator<Jet>,
+    hall: Hall,
+}
+
+impl Chamber {
+    fn new(content: &str) -> Self {
+        Self {
+            rock_types: Generator::new(vec![
+                RockType::Bar,
+                RockType::Star,
+                RockType::Crowbar,
+                RockType::Stick,
+                RockType::Block,
+            ]),
+            jets: Generator::new(parse_jets(content)),
+            hall: Hall::new(),
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        let rock_type = self.rock_types.next();
+        let point = Point::new(2, self.hall.top() as isize + 3);
         let mut free_step = 0;
         let mut rock = Rock::new(rock_type, point);
         loop {
             free_step += 1;
-            let jet = jets.next();
+            let jet = self.jets.next();
             match jet {
                 Jet::L => {
                     if free_step < 3 {
@@ -294,7 +312,7 @@ fn simulate_n_rocks(content: String, amount_of_rocks: usize) -> Hall {
                         }
                     } else {
                         rock.move_left();
-                        if !hall.can_fit(&rock.get_points()) {
+                        if !self.hall.can_fit(rock.get_points()) {
                             rock.move_right();
                         }
                     }
@@ -306,132 +324,128 @@ fn simulate_n_rocks(content: String, amount_of_rocks: usize) -> Hall {
                         }
                     } else {
                         rock.move_right();
-                        if !hall.can_fit(&rock.get_points()) {
+                        if !self.hall.can_fit(rock.get_points()) {
                             rock.move_left();
                         }
                     }
                 }
             }
             rock.move_down();
-            if free_step >= 3 && !hall.can_fit(&rock.get_points()) {
+            if free_step >= 3 && !self.hall.can_fit(rock.get_points()) {
                 rock.move_up();
-                hall.rock_rest(&rock.get_points());
-                hall.ignore_bottom();
-                #[cfg(any(finding_repeat = "sample", finding_repeat = "input"))]
-                log_for_finding_repeat(jets.index, &hall, &mut last_top, _i + 1);
+                self.hall.rock_rest(rock.get_points());
+                self.hall.ignore_bottom();
                 break;
             }
         }
     }
-    hall
+
+    /// The next rock, the next jet and the shape of the top, the rocks to come fall the
+    /// same way whenever these are the same.
+    fn fingerprint(&self) -> (usize, usize, [usize; 7]) {
+        (self.rock_types.index, self.jets.index, self.hall.skyline())
+    }
 }
 
-#[cfg(any(finding_repeat = "sample"))]
-const TEST_INDEX: usize = 1;
-#[cfg(any(finding_repeat = "input"))]
-const TEST_INDEX: usize = 0;
-
-#[cfg(any(finding_repeat = "sample", finding_repeat = "input"))]
-fn log_for_finding_repeat(
-    jets_index: usize,
-    hall: &Hall,
-    last_top: &mut usize,
-    rock_number: usize,
-) {
-    // tweak TEST_INDEX for finding repeat or maybe just log by all jets_index?
-    if jets_index == TEST_INDEX {
-        let next_top = hall.top();
-        let diff = next_top - *last_top;
-        println!("{} ({} => {}), {}", diff, next_top, last_top, rock_number);
-        *last_top = next_top;
+fn simulate_n_rocks(content: &str, amount_of_rocks: usize) -> Hall {
+    let mut chamber = Chamber::new(content);
+    for _ in 0..amount_of_rocks {
+        chamber.drop_rock();
+    }
+    chamber.hall
+}
+
+/// Drops rocks until the chamber comes back to a state it has been in, also returns the
+/// height of the tower after each amount of rocks. The skyline leaves out the holes under
+/// it, so a cycle only counts once the tower grew the same over two more laps.
+fn find_cycle(content: &str) -> (Cycle, Vec<usize>) {
+    let mut chamber = Chamber::new(content);
+    let mut detector = Detector::new();
+    let mut heights = vec![chamber.hall.top()];
+    let mut candidate = detector.record(chamber.fingerprint());
+    loop {
+        chamber.drop_rock();
+        heights.push(chamber.hall.top());
+        let found = detector.record(chamber.fingerprint());
+        match candidate {
+            Some(cycle) if heights.len() > cycle.start + 3 * cycle.length => {
+                if cycle.repeats(&heights, 2) {
+                    return (cycle, heights);
+                }
+                candidate = found;
+            }
+            Some(_) => {}
+            None => candidate = found,
+        }
     }
 }
 
-pub fn process_part1(content: String) -> Option<usize> {
+pub fn process_part1(content: &str) -> Option<usize> {
     let hall = simulate_n_rocks(content, 2022);
     Some(hall.top())
 }
 
-pub fn process_part2(
-    content: String,
-    before_repeat_rock_amount: usize,
-    repeat_rock_amount: usize,
-    repeat_height: usize,
-) -> Option<usize> {
-    let mut rock_amount = 1000000000000usize;
-    let mut repeated_height = 0;
-    while rock_amount > before_repeat_rock_amount {
-        rock_amount -= repeat_rock_amount;
-        repeated_height += repeat_height;
-    }
-    let hall = simulate_n_rocks(content, rock_amount);
-    Some(repeated_height + hall.top())
+pub fn process_part2(content: &str) -> Option<usize> {
+    let (cycle, heights) = find_cycle(content);
+    Some(cycle.extrapolate(&heights, 1000000000000))
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 17;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day17;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(3068), answer);
-    }
+impl Solution for Day17 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 17,
+        title: "Pyroclastic Flow",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(3147), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let multiple = 10000;
-        let answer = process_part2(content, 23 + 35 * multiple, 35 * multiple, 53 * multiple);
-        assert_eq!(Some(1514285714288), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    const DAY_NUMBER: usize = 17;
+    use super::*;
+
+    shared::answer_tests!(Day17);
 
     const BEFORE_REPEAT_ROCK_AMOUNT: usize = 1731;
     const BEFORE_REPEAT_HEIGHT: usize = 2732;
     const REPEAT_ROCK_AMOUNT: usize = 1710;
     const REPEAT_HEIGHT: usize = 2620;
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(
-            content,
-            BEFORE_REPEAT_ROCK_AMOUNT,
-            REPEAT_ROCK_AMOUNT,
-            REPEAT_HEIGHT,
-        );
-        assert_eq!(Some(1532163742758), answer);
-    }
 
     #[test]
+    #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
     fn assert_repeat_data() {
-        let content = read_input(DAY_NUMBER);
-        let hall = simulate_n_rocks(content, BEFORE_REPEAT_ROCK_AMOUNT);
+        let content = shared::read_input(DAY_NUMBER);
+        let hall = simulate_n_rocks(&content, BEFORE_REPEAT_ROCK_AMOUNT);
         assert_eq!(BEFORE_REPEAT_HEIGHT, hall.top());
     }
 
     #[test]
+    #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
     fn assert_repeat_data_2() {
-        let content = read_input(DAY_NUMBER);
-        let hall = simulate_n_rocks(content, BEFORE_REPEAT_ROCK_AMOUNT + REPEAT_ROCK_AMOUNT);
+        let content = shared::read_input(DAY_NUMBER);
+        let hall = simulate_n_rocks(&content, BEFORE_REPEAT_ROCK_AMOUNT + REPEAT_ROCK_AMOUNT);
         assert_eq!(BEFORE_REPEAT_HEIGHT + REPEAT_HEIGHT, hall.top());
     }
 
     #[test]
+    #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
     fn assert_repeat_data_3() {
         let any_number = 314;
-        let content = read_input(DAY_NUMBER);
+        let content = shared::read_input(DAY_NUMBER);
         let hall = simulate_n_rocks(
-            content,
+            &content,
             BEFORE_REPEAT_ROCK_AMOUNT + REPEAT_ROCK_AMOUNT * any_number,
         );
         assert_eq!(
@@ -441,15 +455,16 @@ mod tests {
     }
 
     #[test]
+    #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
     fn assert_repeat_data_4() {
         let any_number = 314;
         let (extra_rocks, height_increased_by_extra_rocks) = (777, 1172);
-        let content = read_input(DAY_NUMBER);
+        let content = shared::read_input(DAY_NUMBER);
         let hall = simulate_n_rocks(
-            content.clone(),
+            &content,
             BEFORE_REPEAT_ROCK_AMOUNT + REPEAT_ROCK_AMOUNT * any_number + extra_rocks,
         );
-        let hall_2 = simulate_n_rocks(content.clone(), BEFORE_REPEAT_ROCK_AMOUNT + extra_rocks);
+        let hall_2 = simulate_n_rocks(&content, BEFORE_REPEAT_ROCK_AMOUNT + extra_rocks);
         assert_eq!(
             BEFORE_REPEAT_HEIGHT + REPEAT_HEIGHT * any_number + height_increased_by_extra_rocks,
             hall.top()
@@ -461,18 +476,19 @@ mod tests {
     }
 
     #[test]
-    #[cfg(finding_repeat = "input")]
-    fn try_find_some_repeat() {
-        let content = read_input(DAY_NUMBER);
-        simulate_n_rocks(content, 3 * 100000);
-        assert!(false);
-    }
-
-    #[test]
-    #[cfg(finding_repeat = "sample")]
-    fn try_find_some_repeat() {
-        let content = read_sample(DAY_NUMBER);
-        simulate_n_rocks(content, 3 * 1000);
-        assert!(false);
+    #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
+    fn test_find_cycle() {
+        let content = shared::read_input(DAY_NUMBER);
+        let (cycle, heights) = find_cycle(&content);
+        assert_eq!(REPEAT_ROCK_AMOUNT, cycle.length);
+        let end = cycle.start + cycle.length;
+        assert_eq!(REPEAT_HEIGHT, heights[end] - heights[cycle.start]);
+        assert_eq!(
+            BEFORE_REPEAT_HEIGHT + REPEAT_HEIGHT * 314,
+            cycle.extrapolate(
+                &heights,
+                BEFORE_REPEAT_ROCK_AMOUNT + REPEAT_ROCK_AMOUNT * 314
+            )
+        );
     }
 }
@@ -1,14 +1,14 @@
 mod parser;
 mod structs;
 
-use parser::parse_line;
+use parser::parse;
+use shared::{Metadata, Solution};
 use structs::{Cube, Space};
 
-fn get_attched_cubes(content: String) -> Vec<Cube> {
+fn get_attched_cubes(content: &str) -> Vec<Cube> {
     let mut cubes = Vec::new();
-    for line in content.lines() {
-        let (x, y, z) = parse_line(line);
-        let mut new_cube = Cube::new(x, y, z);
+    for point in parse(content) {
+        let mut new_cube = Cube::new(point.into());
         for cube in cubes.iter_mut() {
             new_cube.attch(cube);
         }
@@ -17,7 +17,7 @@ fn get_attched_cubes(content: String) -> Vec<Cube> {
     cubes
 }
 
-pub fn process_part1(content: String) -> Option<usize> {
+pub fn process_part1(content: &str) -> Option<usize> {
     let cubes = get_attched_cubes(content);
     let result = cubes
         .into_iter()
@@ -26,49 +26,45 @@ pub fn process_part1(content: String) -> Option<usize> {
     Some(result)
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
     let cubes = get_attched_cubes(content);
     let mut space = Space::from_cubes(cubes);
     space.expend_empty_from_edge();
-    space.draw();
+    shared::trace!("before\n{}", space.render());
     space.fill_uncheck_with_cube();
-    println!("=== after ===");
-    space.draw();
+    shared::trace!("after\n{}", space.render());
     let result = space.get_total_face_count();
     Some(result)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 18;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day18;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(64), answer);
-    }
+impl Solution for Day18 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 18,
+        title: "Boiling Boulders",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(3470), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(58), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(1986), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse(content))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day18);
+}
@@ -1,7 +1,18 @@
-pub fn parse_line(input: &str) -> (usize, usize, usize) {
-    let mut spliter = input.split(",");
-    let x = spliter.next().unwrap().parse().unwrap();
-    let y = spliter.next().unwrap().parse().unwrap();
-    let z = spliter.next().unwrap().parse().unwrap();
-    (x, y, z)
+use nom::{
+    character::complete::char,
+    sequence::{preceded, tuple},
+    IResult,
+};
+use shared::parse::{parse_lines, parsed, unsigned};
+
+fn parse_point(input: &str) -> IResult<&str, (usize, usize, usize)> {
+    tuple((
+        unsigned,
+        preceded(char(','), unsigned),
+        preceded(char(','), unsigned),
+    ))(input)
+}
+
+pub fn parse(input: &str) -> Vec<(usize, usize, usize)> {
+    parsed(parse_lines(input, parse_point))
 }
@@ -1,4 +1,7 @@
-use std::{cell::RefCell, collections::HashSet, ops::Range};
+use shared::geom::{Bounds3, Point3};
+use std::{cell::RefCell, collections::HashSet};
+
+type Point = Point3<usize>;
 
 #[derive(Clone)]
 pub enum SpaceType {
@@ -8,59 +11,51 @@ pub enum SpaceType {
 }
 
 pub struct Space {
-    pub boundary: Boundary,
+    pub boundary: Bounds3<usize>,
     pub space: Vec<Vec<Vec<SpaceType>>>,
 }
 
 impl Space {
     pub fn from_cubes(cubes: Vec<Cube>) -> Self {
-        let boundary = Boundary::from_cubes(&cubes);
-        let mut space =
-            vec![
-                vec![vec![SpaceType::Unchecked; boundary.max_z + 1]; boundary.max_y + 1];
-                boundary.max_x + 1
-            ];
+        let boundary = Bounds3::from_points(cubes.iter().map(|cube| cube.point)).unwrap();
+        let max = boundary.max;
+        let mut space = vec![vec![vec![SpaceType::Unchecked; max.z + 1]; max.y + 1]; max.x + 1];
         for cube in cubes {
-            let x = cube.x;
-            let y = cube.y;
-            let z = cube.z;
+            let Point { x, y, z } = cube.point;
             space[x][y][z] = SpaceType::Occupied(RefCell::new(cube));
         }
         Self { space, boundary }
     }
 
-    pub fn draw(&mut self) {
-        let x_width = self.boundary.x_width();
-        for z in self.boundary.z_range() {
-            println!(" z: {}", z);
-            println!("+{:-<1$}+", "", x_width);
-            for y in self.boundary.y_range() {
-                print!("|");
-                for x in self.boundary.x_range() {
+    pub fn render(&mut self) -> String {
+        let Bounds3 { min, max } = self.boundary;
+        let x_width = self.boundary.width();
+        let mut lines = Vec::new();
+        for z in min.z..=max.z {
+            lines.push(format!(" z: {}", z));
+            lines.push(format!("+{:-<1$}+", "", x_width));
+            for y in min.y..=max.y {
+                let mut line = String::from("|");
+                for x in min.x..=max.x {
                     let point = Point::new(x, y, z);
-                    match self.get_space(&point) {
-                        SpaceType::Unchecked => print!("?"),
-                        SpaceType::Occupied(_) => print!("#"),
-                        SpaceType::Empty => print!("."),
-                    }
+                    line.push(match self.get_space(&point) {
+                        SpaceType::Unchecked => '?',
+                        SpaceType::Occupied(_) => '#',
+                        SpaceType::Empty => '.',
+                    });
                 }
-                print!("|\n")
+                line.push('|');
+                lines.push(line);
             }
-            println!("+{:-<1$}+", "", x_width);
+            lines.push(format!("+{:-<1$}+", "", x_width));
         }
+        lines.join("\n")
     }
 
     pub fn expend_empty_from_edge(&mut self) {
-        for z in self.boundary.z_range() {
-            for y in self.boundary.y_range() {
-                for x in self.boundary.x_range() {
-                    let point = Point::new(x, y, z);
-                    if let SpaceType::Unchecked = self.get_space(&point) {
-                        self.fill_empty(point);
-                    } else {
-                        continue;
-                    }
-                }
+        for point in self.boundary.points() {
+            if let SpaceType::Unchecked = self.get_space(&point) {
+                self.fill_empty(point);
             }
         }
     }
@@ -73,11 +68,11 @@ impl Space {
                 break;
             }
             let current = points.pop().unwrap();
-            if self.boundary.is_edge(&current) {
+            if self.boundary.is_edge(current) {
                 self.set_space(&current, SpaceType::Empty);
             }
             if let SpaceType::Empty = self.get_space(&current) {
-                let neighbors = self.boundary.neighbors_from(&current);
+                let neighbors = self.neighbors_from(current);
                 for neighbor in neighbors {
                     if visited.contains(&neighbor) {
                         continue;
@@ -92,6 +87,13 @@ impl Space {
         }
     }
 
+    fn neighbors_from(&self, point: Point) -> Vec<Point> {
+        point
+            .neighbours6()
+            .filter(|neighbor| self.boundary.contains(*neighbor))
+            .collect()
+    }
+
     fn get_space(&self, point: &Point) -> &SpaceType {
         &self.space[point.x][point.y][point.z]
     }
@@ -101,168 +103,32 @@ impl Space {
     }
 
     pub fn fill_uncheck_with_cube(&mut self) {
-        for z in self.boundary.z_range() {
-            for y in self.boundary.y_range() {
-                for x in self.boundary.x_range() {
-                    let point = Point::new(x, y, z);
-                    if let SpaceType::Unchecked = self.get_space(&point) {
-                        let mut new_cube = Cube::new(x, y, z);
-                        let neighbors = self.boundary.neighbors_from(&point);
-                        for neighbor in neighbors {
-                            if let SpaceType::Occupied(cube) = self.get_space(&neighbor) {
-                                let mut cube = cube.borrow_mut();
-                                new_cube.attch(&mut cube);
-                            }
-                        }
-                        self.set_space(&point, SpaceType::Occupied(RefCell::new(new_cube)));
+        for point in self.boundary.points() {
+            if let SpaceType::Unchecked = self.get_space(&point) {
+                let mut new_cube = Cube::new(point);
+                let neighbors = self.neighbors_from(point);
+                for neighbor in neighbors {
+                    if let SpaceType::Occupied(cube) = self.get_space(&neighbor) {
+                        let mut cube = cube.borrow_mut();
+                        new_cube.attch(&mut cube);
                     }
                 }
+                self.set_space(&point, SpaceType::Occupied(RefCell::new(new_cube)));
             }
         }
     }
 
     pub fn get_total_face_count(&self) -> usize {
         let mut total_face_count = 0;
-        for z in self.boundary.z_range() {
-            for y in self.boundary.y_range() {
-                for x in self.boundary.x_range() {
-                    let point = Point::new(x, y, z);
-                    if let SpaceType::Occupied(cube) = self.get_space(&point) {
-                        total_face_count += cube.borrow().get_face_count();
-                    }
-                }
+        for point in self.boundary.points() {
+            if let SpaceType::Occupied(cube) = self.get_space(&point) {
+                total_face_count += cube.borrow().get_face_count();
             }
         }
         total_face_count
     }
 }
 
-#[derive(Debug)]
-pub struct Boundary {
-    pub min_x: usize,
-    pub max_x: usize,
-    pub min_y: usize,
-    pub max_y: usize,
-    pub min_z: usize,
-    pub max_z: usize,
-}
-
-impl Boundary {
-    pub fn from_cubes(cubes: &Vec<Cube>) -> Self {
-        let mut min_x = usize::MAX;
-        let mut max_x = 0;
-        let mut min_y = usize::MAX;
-        let mut max_y = 0;
-        let mut min_z = usize::MAX;
-        let mut max_z = 0;
-        for cube in cubes {
-            if cube.x < min_x {
-                min_x = cube.x;
-            }
-            if cube.x > max_x {
-                max_x = cube.x;
-            }
-            if cube.y < min_y {
-                min_y = cube.y;
-            }
-            if cube.y > max_y {
-                max_y = cube.y;
-            }
-            if cube.z < min_z {
-                min_z = cube.z;
-            }
-            if cube.z > max_z {
-                max_z = cube.z;
-            }
-        }
-        Self {
-            min_x,
-            max_x,
-            min_y,
-            max_y,
-            min_z,
-            max_z,
-        }
-    }
-
-    fn x_range(&self) -> Range<usize> {
-        self.min_x..(self.max_x + 1)
-    }
-
-    fn y_range(&self) -> Range<usize> {
-        self.min_y..(self.max_y + 1)
-    }
-
-    fn z_range(&self) -> Range<usize> {
-        self.min_z..(self.max_z + 1)
-    }
-
-    fn is_edge(&self, point: &Point) -> bool {
-        point.x == self.min_x
-            || point.x == self.max_x
-            || point.y == self.min_y
-            || point.y == self.max_y
-            || point.z == self.min_z
-            || point.z == self.max_z
-    }
-
-    fn is_out(&self, point: &Point) -> bool {
-        point.x < self.min_x
-            || point.x > self.max_x
-            || point.y < self.min_y
-            || point.y > self.max_y
-            || point.z < self.min_z
-            || point.z > self.max_z
-    }
-
-    fn neighbors_from(&self, point: &Point) -> Vec<Point> {
-        let points = vec![
-            point.diff(1, 0, 0),
-            point.diff(-1, 0, 0),
-            point.diff(0, 1, 0),
-            point.diff(0, -1, 0),
-            point.diff(0, 0, 1),
-            point.diff(0, 0, -1),
-        ];
-        points
-            .into_iter()
-            .filter_map(|point| point)
-            .filter(|point| !self.is_out(&point))
-            .collect::<Vec<_>>()
-    }
-
-    fn x_width(&self) -> usize {
-        (self.max_x - self.min_x) + 1
-    }
-}
-
-#[derive(Eq, PartialEq, Hash)]
-struct Point {
-    pub x: usize,
-    pub y: usize,
-    pub z: usize,
-}
-
-impl Point {
-    pub fn new(x: usize, y: usize, z: usize) -> Self {
-        Self { x, y, z }
-    }
-
-    fn diff(&self, x: isize, y: isize, z: isize) -> Option<Self> {
-        let x = (self.x as isize) + x;
-        let y = (self.y as isize) + y;
-        let z = (self.z as isize) + z;
-        if x < 0 || y < 0 || z < 0 {
-            return None;
-        }
-        Some(Self {
-            x: x as usize,
-            y: y as usize,
-            z: z as usize,
-        })
-    }
-}
-
 enum Side {
     // -x
     L = 0,
@@ -280,18 +146,14 @@ enum Side {
 
 #[derive(Clone)]
 pub struct Cube {
-    pub x: usize,
-    pub y: usize,
-    pub z: usize,
+    pub point: Point,
     sides: [bool; 6],
 }
 
 impl Cube {
-    pub fn new(x: usize, y: usize, z: usize) -> Self {
+    pub fn new(point: Point) -> Self {
         Self {
-            x,
-            y,
-            z,
+            point,
             sides: [true; 6],
         }
     }
@@ -307,33 +169,32 @@ impl Cube {
     }
 
     pub fn attch(&mut self, other: &mut Cube) {
-        let diff_x = self.x.abs_diff(other.x);
-        let diff_y = self.y.abs_diff(other.y);
-        let diff_z = self.z.abs_diff(other.z);
-        if diff_x == 1 && diff_y == 0 && diff_z == 0 {
-            if self.x < other.x {
+        let (this, other_point) = (self.point, other.point);
+        if this.manhattan(other_point) != 1 {
+            return;
+        }
+        if this.x != other_point.x {
+            if this.x < other_point.x {
                 self.remove(Side::R);
                 other.remove(Side::L);
             } else {
                 self.remove(Side::L);
                 other.remove(Side::R);
             }
-        } else if diff_x == 0 && diff_y == 1 && diff_z == 0 {
-            if self.y < other.y {
+        } else if this.y != other_point.y {
+            if this.y < other_point.y {
                 self.remove(Side::U);
                 other.remove(Side::D);
             } else {
                 self.remove(Side::D);
                 other.remove(Side::U);
             }
-        } else if diff_x == 0 && diff_y == 0 && diff_z == 1 {
-            if self.z < other.z {
-                self.remove(Side::F);
-                other.remove(Side::B);
-            } else {
-                self.remove(Side::B);
-                other.remove(Side::F);
-            }
+        } else if this.z < other_point.z {
+            self.remove(Side::F);
+            other.remove(Side::B);
+        } else {
+            self.remove(Side::B);
+            other.remove(Side::F);
         }
     }
 
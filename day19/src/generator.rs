@@ -0,0 +1,22 @@
+use shared::random::Rng;
+
+/// `size` blueprints with costs in the ranges of the puzzle. Part 2 only looks at the
+/// first three, so a bigger size only makes part 1 slower.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.\n",
+                id,
+                rng.usize_in(2..=4),
+                rng.usize_in(2..=4),
+                rng.usize_in(2..=4),
+                rng.usize_in(5..=20),
+                rng.usize_in(2..=4),
+                rng.usize_in(7..=20),
+            )
+        })
+        .collect()
+}
@@ -1,12 +1,21 @@
+mod generator;
 mod parser;
 mod structs;
 
 use parser::parse;
-use shared::log;
+use shared::{
+    progress::{Cancelled, Progress},
+    random::Rng,
+    Metadata, Solution,
+};
 use std::collections::HashSet;
 use structs::{Blueprint, State};
 
-fn simulate(blueprint: &Blueprint, time: usize) -> usize {
+fn simulate(
+    blueprint: &Blueprint,
+    time: usize,
+    progress: &mut Progress,
+) -> Result<usize, Cancelled> {
     let mut first_geode_robot = None;
     let mut second_geode_robot = None;
     let mut third_geode_robot = None;
@@ -23,105 +32,123 @@ fn simulate(blueprint: &Blueprint, time: usize) -> usize {
             }
         }
         futures = next_futures.into_iter().collect();
-        log(format!("{}: {}", i + 1, futures.len()).as_str());
+        shared::debug!("{}: {} states", i + 1, futures.len());
+        progress.inc(1)?;
         if first_geode_robot.is_none() {
             if futures.iter().any(|state| state.geode_robots == 1) {
-                log(format!("{}: first geode robot!", i + 1).as_str());
+                shared::debug!("{}: first geode robot!", i + 1);
                 first_geode_robot = Some(i);
             }
         } else {
             let time = first_geode_robot.unwrap();
             if time + 2 == i {
-                log(format!("{}: time to eliminate!(1)", i + 1).as_str());
+                shared::debug!("{}: time to eliminate!(1)", i + 1);
                 futures.retain(|state| state.geode_robots > 0);
             }
         }
         if second_geode_robot.is_none() {
             if futures.iter().any(|state| state.geode_robots == 2) {
-                log(format!("{}: second geode robot!", i + 1).as_str());
+                shared::debug!("{}: second geode robot!", i + 1);
                 second_geode_robot = Some(i);
             }
         } else {
             let time = second_geode_robot.unwrap();
             if time + 3 == i {
-                log(format!("{}: time to eliminate!(2)", i + 1).as_str());
+                shared::debug!("{}: time to eliminate!(2)", i + 1);
                 futures.retain(|state| state.geode_robots > 1);
             }
         }
         if third_geode_robot.is_none() {
             if futures.iter().any(|state| state.geode_robots == 3) {
-                log(format!("{}: third geode robot!", i + 1).as_str());
+                shared::debug!("{}: third geode robot!", i + 1);
                 third_geode_robot = Some(i);
             }
         } else {
             let time = third_geode_robot.unwrap();
             if time + 4 == i {
-                log(format!("{}: time to eliminate!(3)", i + 1).as_str());
+                shared::debug!("{}: time to eliminate!(3)", i + 1);
                 futures.retain(|state| state.geode_robots > 2);
             }
         }
     }
-    futures.into_iter().map(|state| state.geode).max().unwrap()
+    Ok(futures.into_iter().map(|state| state.geode).max().unwrap())
 }
 
-pub fn process_part1(content: String) -> Option<usize> {
-    let blueprints = parse(&content);
+pub fn process_part1(content: &str) -> Option<usize> {
+    let blueprints = parse(content);
+    let mut progress = Progress::new("day19 part 1", blueprints.len() as u64 * 24);
     let result = blueprints
         .iter()
         .map(|blueprint| {
-            let geode = simulate(blueprint, 24);
-            dbg!(blueprint.id, geode);
-            geode * blueprint.id
+            let geode = simulate(blueprint, 24, &mut progress)?;
+            shared::debug!("blueprint {} opens {} geodes", blueprint.id, geode);
+            Ok(geode * blueprint.id)
         })
-        .collect::<Vec<usize>>();
-    Some(result.iter().sum())
+        .collect::<Result<Vec<usize>, Cancelled>>();
+    finish(progress, result).map(|result| result.iter().sum())
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
-    let blueprints = parse(&content);
+pub fn process_part2(content: &str) -> Option<usize> {
+    let blueprints = parse(content);
+    let mut progress = Progress::new("day19 part 2", blueprints.len().min(3) as u64 * 32);
     let result = blueprints
         .iter()
         .take(3)
         .map(|blueprint| {
-            let geode = simulate(blueprint, 32);
-            dbg!(blueprint.id, geode);
-            geode
+            let geode = simulate(blueprint, 32, &mut progress)?;
+            shared::debug!("blueprint {} opens {} geodes", blueprint.id, geode);
+            Ok(geode)
         })
-        .collect::<Vec<usize>>();
-    Some(result.iter().product())
+        .collect::<Result<Vec<usize>, Cancelled>>();
+    finish(progress, result).map(|result| result.iter().product())
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 19;
-    use super::*;
-    use shared::{read_input, read_sample};
+/// A cancelled search has no answer.
+fn finish(progress: Progress, result: Result<Vec<usize>, Cancelled>) -> Option<Vec<usize>> {
+    match result {
+        Ok(result) => {
+            progress.finish();
+            Some(result)
+        }
+        Err(cancelled) => {
+            shared::warn!("{}", cancelled);
+            None
+        }
+    }
+}
+
+pub struct Day19;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(33), answer);
+impl Solution for Day19 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 19,
+        title: "Not Enough Minerals",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(960), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(3472), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse(content))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(2040), answer);
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day19);
+}
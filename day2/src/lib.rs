@@ -1,4 +1,4 @@
-use shared::read_file;
+use shared::{Metadata, Solution};
 
 enum Chose {
     Rock,
@@ -66,15 +66,17 @@ impl Game {
     }
 }
 
-fn main() {
-    let content = read_file();
-    // day 2 - 1
-    play_strategy(&content, parse_strategy1);
-    // day 2 - 2
-    play_strategy(&content, parse_strategy2);
+pub fn process_part1(content: &str) -> Option<usize> {
+    let game = play_strategy(content, parse_strategy1);
+    Some(game.mine_score)
 }
 
-fn play_strategy(content: &String, parse_round: fn(&str) -> Option<Round>) {
+pub fn process_part2(content: &str) -> Option<usize> {
+    let game = play_strategy(content, parse_strategy2);
+    Some(game.mine_score)
+}
+
+fn play_strategy(content: &str, parse_round: fn(&str) -> Option<Round>) -> Game {
     let mut rounds = Vec::new();
     for line in content.lines() {
         if let Some(round) = parse_round(line) {
@@ -83,11 +85,11 @@ fn play_strategy(content: &String, parse_round: fn(&str) -> Option<Round>) {
     }
     let mut game = Game::new();
     rounds.iter().for_each(|round| game.play_round(round));
-    println!("{:?}", game);
+    game
 }
 
 fn parse_strategy1(line: &str) -> Option<Round> {
-    let mut split = line.split(" ");
+    let mut split = line.split(' ');
     let opponent = split.next()?;
     let mine = split.next()?;
     let opponent_chose = match opponent {
@@ -109,7 +111,7 @@ fn parse_strategy1(line: &str) -> Option<Round> {
 }
 
 fn parse_strategy2(line: &str) -> Option<Round> {
-    let mut split = line.split(" ");
+    let mut split = line.split(' ');
     let opponent = split.next()?;
     let outcome = split.next()?;
     let opponent_chose = match opponent {
@@ -140,3 +142,31 @@ fn parse_strategy2(line: &str) -> Option<Round> {
         mine_chose,
     })
 }
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 2,
+        title: "Rock Paper Scissors",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
+    }
+
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day2);
+}
@@ -0,0 +1,19 @@
+use shared::random::Rng;
+
+/// `size` numbers, at least two, in the range of the puzzle with duplicates and a single 0
+/// among them.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut numbers = (1..size.max(2))
+        .map(|_| loop {
+            let number = rng.isize_in(-10_000..=10_000);
+            if number != 0 {
+                break number;
+            }
+        })
+        .collect::<Vec<isize>>();
+    numbers.insert(rng.usize_in(0..=numbers.len()), 0);
+    numbers
+        .iter()
+        .map(|number| format!("{}\n", number))
+        .collect()
+}
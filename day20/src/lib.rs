@@ -1,3 +1,6 @@
+mod generator;
+
+use shared::{random::Rng, Metadata, Solution};
 use std::{collections::VecDeque, fmt::Debug};
 
 #[derive(Clone)]
@@ -34,7 +37,7 @@ fn get_coordinate_sum(list: Vec<Cell>) -> isize {
     for poition in [1000, 2000, 3000] {
         let poition = (poition + zero_poition) % loop_length;
         let cell = &list[poition];
-        dbg!(cell.number);
+        shared::debug!("number at {} is {}", poition, cell.number);
         coordinate_sum += cell.number;
     }
     coordinate_sum
@@ -48,9 +51,9 @@ fn parse(content: &str) -> VecDeque<Cell> {
         .collect()
 }
 
-pub fn process_part1(content: String) -> Option<isize> {
+pub fn process_part1(content: &str) -> Option<isize> {
     let mut checked_count = 0;
-    let mut list = parse(&content);
+    let mut list = parse(content);
     let loop_length = (list.len() as isize) - 1;
     loop {
         if checked_count > loop_length {
@@ -70,9 +73,9 @@ pub fn process_part1(content: String) -> Option<isize> {
     Some(get_coordinate_sum(list.into()))
 }
 
-pub fn process_part2(content: String) -> Option<isize> {
+pub fn process_part2(content: &str) -> Option<isize> {
     let decryption_key = 811589153;
-    let mut list: Vec<Cell> = parse(&content).into();
+    let mut list: Vec<Cell> = parse(content).into();
     list.iter_mut()
         .for_each(|cell| cell.number *= decryption_key);
     let ordered_cells = list.clone();
@@ -93,37 +96,38 @@ pub fn process_part2(content: String) -> Option<isize> {
     Some(get_coordinate_sum(list))
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 20;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day20;
+
+impl Solution for Day20 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 20,
+        title: "Grove Positioning System",
+    };
+    type Params = ();
+    type Answer1 = isize;
+    type Answer2 = isize;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(3), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(8372), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(1623178306), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse(content))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(7865110481723), answer);
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day20);
+}
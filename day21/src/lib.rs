@@ -1,6 +1,7 @@
 mod parser;
 
 use parser::parse;
+use shared::{Metadata, Solution};
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 #[derive(Debug, Clone)]
@@ -42,7 +43,7 @@ impl Number {
             (Number::M(a), Number::M(b)) => Number::M(a / b),
             (Number::M(_), Number::H(_, _, _)) => panic!("Have to Find the Inverse F(x)!"),
             (Number::H(m, d, a), Number::M(b)) => {
-                dbg!(a, *b as f64, a / *b as f64);
+                shared::trace!("{} / {} = {}", a, b, a / *b as f64);
                 Number::H(*m, d * b, a / *b as f64)
             }
             (Number::H(_, _, _), Number::H(_, _, _)) => panic!("Two human!"),
@@ -153,50 +154,47 @@ fn fix_mistranslation(hash_map: &mut HashMap<String, MonkeyRecord>) {
     *monkey_type = new_monkey_type;
 }
 
-pub fn process_part1(content: String) -> Option<isize> {
-    let hash_map = parse(&content);
+pub fn process_part1(content: &str) -> Option<isize> {
+    let hash_map = parse(content);
     let root = hash_map.get("root").unwrap();
     ask_from_root(root, &hash_map)
 }
 
-pub fn process_part2(content: String) -> Option<isize> {
-    let mut hash_map = parse(&content);
+pub fn process_part2(content: &str) -> Option<isize> {
+    let mut hash_map = parse(content);
     fix_mistranslation(&mut hash_map);
     let root = hash_map.get("root").unwrap();
     ask_from_root(root, &hash_map)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 21;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day21;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(152), answer);
-    }
+impl Solution for Day21 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 21,
+        title: "Monkey Math",
+    };
+    type Params = ();
+    type Answer1 = isize;
+    type Answer2 = isize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(81075092088442), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(301), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(3349136384441), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse(content))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day21);
+}
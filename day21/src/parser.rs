@@ -2,10 +2,11 @@ use crate::{MonkeyRecord, MonkeyType, Number, OperationType};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{alpha1, digit1, one_of, space0},
+    character::complete::{alpha1, one_of, space0},
     sequence::{delimited, separated_pair, tuple},
     IResult,
 };
+use shared::parse::{parse_lines, parsed, unsigned};
 use std::collections::HashMap;
 
 fn operation_type(input: &str) -> IResult<&str, OperationType> {
@@ -28,8 +29,7 @@ fn monkey_type_operator(input: &str) -> IResult<&str, MonkeyRecord> {
 }
 
 fn monkey_type_yell(input: &str) -> IResult<&str, MonkeyRecord> {
-    let (input, (name, number)) = separated_pair(alpha1, tag(": "), digit1)(input)?;
-    let number = number.parse().unwrap();
+    let (input, (name, number)) = separated_pair(alpha1, tag(": "), unsigned)(input)?;
     let monkey_type = MonkeyType::Yell(Number::M(number));
     Ok((input, MonkeyRecord::new(name.into(), monkey_type)))
 }
@@ -40,10 +40,8 @@ fn parse_line(input: &str) -> IResult<&str, MonkeyRecord> {
 }
 
 pub(crate) fn parse(input: &str) -> HashMap<String, MonkeyRecord> {
-    let mut hash_map = HashMap::new();
-    for line in input.lines() {
-        let (_, monkey) = parse_line(line).unwrap();
-        hash_map.insert(monkey.name.clone(), monkey);
-    }
-    hash_map
+    parsed(parse_lines(input, parse_line))
+        .into_iter()
+        .map(|monkey| (monkey.name.clone(), monkey))
+        .collect()
 }
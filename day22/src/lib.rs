@@ -2,53 +2,66 @@ mod parser;
 mod structs;
 
 use parser::{parse_part1, parse_part2};
+use serde::Deserialize;
+use shared::{Metadata, Solution};
 
-pub fn process_part1(content: String) -> Option<usize> {
-    let mut monkey_map = parse_part1(&content);
+pub fn process_part1(content: &str) -> Option<usize> {
+    let mut monkey_map = parse_part1(content);
     monkey_map.follow_path();
     let row = (monkey_map.current_point.y + 1) as usize;
     let column = (monkey_map.current_point.x + 1) as usize;
-    dbg!(row, column, &monkey_map.current_facing);
+    shared::debug!(
+        "row {}, column {}, {:?}",
+        row,
+        column,
+        monkey_map.current_facing
+    );
     Some(1000 * row + 4 * column + monkey_map.current_facing as usize)
 }
 
-pub fn process_part2(content: String, face_size: usize) -> Option<usize> {
-    let mut human_map = parse_part2(&content, face_size);
+pub fn process_part2(content: &str, face_size: usize) -> Option<usize> {
+    let mut human_map = parse_part2(content, face_size);
     human_map.follow_path();
     human_map.get_result()
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 22;
-    use super::*;
-    use shared::{read_input, read_sample};
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Params {
+    /// The cube of the sample is smaller than the one of the input, see `params.toml`.
+    pub face_size: usize,
+}
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(6032), answer);
+impl Default for Params {
+    fn default() -> Self {
+        Self { face_size: 50 }
     }
+}
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(77318), answer);
-    }
+pub struct Day22;
+
+impl Solution for Day22 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 22,
+        title: "Monkey Map",
+    };
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content, 4);
-        assert_eq!(Some(5031), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content, 50);
-        assert_eq!(Some(126017), answer);
+    fn part2(content: &str, params: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content, params.face_size)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day22);
+}
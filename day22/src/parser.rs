@@ -1,58 +1,58 @@
 use crate::structs::{HumanMap, MonkeyMap, StepType, TileType};
-use nom::{branch::alt, bytes::complete::tag, character::complete::digit1, multi::many1, IResult};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, one_of},
+    combinator::map,
+    multi::{many1, separated_list1},
+    sequence::{pair, separated_pair},
+    IResult,
+};
+use shared::parse::{parse_all, parsed, unsigned};
 
-fn parse_full_map(lines: &mut std::str::Lines) -> Vec<Vec<TileType>> {
-    let mut full_map = Vec::new();
-    let mut x_size = 0;
-    loop {
-        let line = lines.next().unwrap();
-        if line == "" {
-            break;
-        }
-        let mut row = Vec::new();
-        for char in line.chars() {
-            let tile_type = match char {
-                ' ' => TileType::Void,
-                '#' => TileType::Wall,
-                '.' => TileType::Open,
-                _ => panic!("Unknow Tile Type: {}!", char),
-            };
-            row.push(tile_type);
-        }
-        if row.len() > x_size {
-            x_size = row.len();
-        }
-        full_map.push(row);
-    }
-    let mut full_map = full_map;
+fn parse_tile(input: &str) -> IResult<&str, TileType> {
+    let (input, char) = one_of(" #.")(input)?;
+    let tile_type = match char {
+        ' ' => TileType::Void,
+        '#' => TileType::Wall,
+        _ => TileType::Open,
+    };
+    Ok((input, tile_type))
+}
+
+/// The rows of the map, the short ones filled up with void to the widest one.
+fn parse_full_map(input: &str) -> IResult<&str, Vec<Vec<TileType>>> {
+    let (input, mut full_map) = separated_list1(line_ending, many1(parse_tile))(input)?;
+    let x_size = full_map.iter().map(Vec::len).max().unwrap_or(0);
     for row in full_map.iter_mut() {
-        while row.len() < x_size {
-            row.push(TileType::Void);
-        }
+        row.resize(x_size, TileType::Void);
     }
-    for row in full_map.iter() {
-        for tile_type in row.iter() {
-            let char = match tile_type {
-                TileType::Void => '⬛',
-                TileType::Wall => '🌚',
-                TileType::Open => '⬜',
-            };
-            print!("{}", char);
-        }
-        println!();
-    }
-    println!();
+    shared::trace!("\n{}", render_full_map(&full_map));
+    Ok((input, full_map))
+}
+
+fn render_full_map(full_map: &[Vec<TileType>]) -> String {
     full_map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|tile_type| match tile_type {
+                    TileType::Void => '⬛',
+                    TileType::Wall => '🌚',
+                    TileType::Open => '⬜',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
 fn parse_step(input: &str) -> IResult<&str, StepType> {
-    let (input, step) = alt((digit1, tag("L"), tag("R")))(input)?;
-    let step = match step {
-        "L" => StepType::TurnLeft,
-        "R" => StepType::TurnRight,
-        _ => StepType::Forward(step.parse().unwrap()),
-    };
-    Ok((input, step))
+    alt((
+        map(unsigned, StepType::Forward),
+        map(tag("L"), |_| StepType::TurnLeft),
+        map(tag("R"), |_| StepType::TurnRight),
+    ))(input)
 }
 
 fn parse_path(input: &str) -> IResult<&str, Vec<StepType>> {
@@ -60,16 +60,47 @@ fn parse_path(input: &str) -> IResult<&str, Vec<StepType>> {
     Ok((input, step))
 }
 
+/// The map and the path below it, after a blank line.
+fn parse_map_and_path(input: &str) -> (Vec<Vec<TileType>>, Vec<StepType>) {
+    let map_and_path = separated_pair(parse_full_map, pair(line_ending, line_ending), parse_path);
+    parsed(parse_all(input, map_and_path))
+}
+
 pub fn parse_part1(input: &str) -> MonkeyMap {
-    let mut lines = input.lines();
-    let full_map = parse_full_map(&mut lines);
-    let (_, path) = parse_path(lines.next().unwrap()).unwrap();
+    let (full_map, path) = parse_map_and_path(input);
     MonkeyMap::new(full_map, path)
 }
 
 pub fn parse_part2(input: &str, face_size: usize) -> HumanMap {
-    let mut lines = input.lines();
-    let full_map = parse_full_map(&mut lines);
-    let (_, path) = parse_path(lines.next().unwrap()).unwrap();
+    let (full_map, path) = parse_map_and_path(input);
     HumanMap::new(full_map, path, face_size)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_map() {
+        let (rest, full_map) = parse_full_map("  .#\n...\n\n10R5").unwrap();
+        assert_eq!("\n\n10R5", rest);
+        assert_eq!(
+            vec![4, 4],
+            full_map.iter().map(Vec::len).collect::<Vec<_>>()
+        );
+        assert!(matches!(full_map[1][3], TileType::Void));
+        let mut map_and_path =
+            separated_pair(parse_full_map, pair(line_ending, line_ending), parse_path);
+        let err = parse_all("..\n.x\n\n10R5", &mut map_and_path)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+        let err = parse_all("..\n..\n\n10R99999999999999999999L", map_and_path)
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(
+            (4, 4, "this does not fit in its type"),
+            (err.line, err.column, err.message.as_str())
+        );
+    }
+}
@@ -70,7 +70,7 @@ impl<T: Copy + Debug> Point<T> {
 
     fn set(&mut self, x_and_y: (T, T)) {
         let (x, y) = x_and_y;
-        println!("{:?}, {:?}", x, y);
+        shared::trace!("{:?}, {:?}", x, y);
         self.x = x;
         self.y = y;
     }
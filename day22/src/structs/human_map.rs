@@ -24,16 +24,15 @@ impl MapFace {
         matches!(self.map[0][0], TileType::Void)
     }
 
-    fn draw(&self) {
+    fn render(&self) -> String {
         if let TileType::Void = self.map[0][0] {
-            println!("== VOID ==");
-            println!();
-            return;
+            return String::from("== VOID ==");
         }
         let mut set = HashSet::new();
         self.histories.iter().for_each(|point| {
             set.insert(point.tuple());
         });
+        let mut lines = Vec::new();
         for (y, row) in self.map.iter().enumerate() {
             let mut chars = Vec::new();
             for (x, tile_type) in row.iter().enumerate() {
@@ -50,9 +49,9 @@ impl MapFace {
             }
             let size = chars.len();
             let string = String::from_iter(chars.into_iter());
-            println!("{}, {}", size, string);
+            lines.push(format!("{}, {}", size, string));
         }
-        println!();
+        lines.join("\n")
     }
 }
 
@@ -294,7 +293,7 @@ impl HumanMap {
             map_faces.push(row);
         }
         let map_cube = build_map_cube(x_max, map_faces, y_max);
-        dbg!(&map_cube);
+        shared::trace!("{:?}", map_cube);
         Self {
             face_size,
             current_point: Point::new(0, 0),
@@ -320,7 +319,7 @@ impl HumanMap {
 
     fn move_forward(&mut self, step_count: usize) {
         let mut step_count = step_count;
-        println!("{} step", step_count);
+        shared::trace!("{} step", step_count);
         while step_count > 0 {
             let (tile_type, (x, y)) = self.get_next_tile_type();
             match tile_type {
@@ -393,23 +392,18 @@ impl HumanMap {
                     Direction::Up => self.map_cube.cube_rotate(&Direction::Down),
                 }
             } else {
-                println!();
+                shared::trace!("onto the next face");
             }
         }
         (tile_type, (x, y))
     }
 
     pub fn get_result(&mut self) -> Option<usize> {
-        dbg!(&self.current_point);
-        dbg!(&self.current_facing);
-        println!("{:?}", &self.map_cube.map_faces[0]);
-        println!("================");
-        self.map_cube.map_faces[1].draw();
-        self.map_cube.map_faces[0].draw();
-        self.map_cube.map_faces[4].draw();
-        self.map_cube.map_faces[5].draw();
-        self.map_cube.map_faces[2].draw();
-        self.map_cube.map_faces[3].draw();
+        shared::debug!("{:?} {:?}", self.current_point, self.current_facing);
+        shared::trace!("{:?}", self.map_cube.map_faces[0]);
+        for index in [1, 0, 4, 5, 2, 3] {
+            shared::trace!("\n{}", self.map_cube.map_faces[index].render());
+        }
 
         let mut current_point = self.current_point.clone();
         let mut current_facing = self.current_facing.clone();
@@ -436,9 +430,8 @@ impl HumanMap {
             Direction::Up => (),
         }
 
-        dbg!(&current_point);
-        dbg!(&current_facing);
-        println!("{:?}", &current_face);
+        shared::debug!("{:?} {:?}", current_point, current_facing);
+        shared::trace!("{:?}", current_face);
 
         let row = current_face.point.y + current_point.y + 1;
         let column = current_face.point.x + current_point.x + 1;
@@ -467,7 +460,7 @@ fn build_map_cube(x_max: usize, map_faces: Vec<Vec<MapFace>>, y_max: usize) -> M
         if map_face.is_void() {
             continue;
         }
-        map_face.draw();
+        shared::trace!("first face\n{}", map_face.render());
         current_point.set((x, y));
         break;
     }
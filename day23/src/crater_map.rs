@@ -1,7 +1,7 @@
 use crate::structs::{Boundary, Coordinate, Creature, Neighbors};
 use std::collections::HashMap;
 
-pub fn parse_map(content: String) -> HashMap<Coordinate, Creature> {
+pub fn parse_map(content: &str) -> HashMap<Coordinate, Creature> {
     let mut map = HashMap::new();
     for (y, line) in content.lines().enumerate() {
         for (x, char) in line.chars().enumerate() {
@@ -35,9 +35,11 @@ pub fn count_empty_grounds(map: &HashMap<Coordinate, Creature>) -> usize {
     area - map.len()
 }
 
-pub fn draw_map(map: &HashMap<Coordinate, Creature>) {
+pub fn render_map(map: &HashMap<Coordinate, Creature>) -> String {
     let (min_x, max_x, min_y, max_y) = get_boundary(map);
+    let mut lines = Vec::new();
     for y in min_y..=max_y {
+        let mut line = String::new();
         for x in min_x..=max_x {
             let mut char = match map.get(&(x, y)) {
                 Some(_) => '😀',
@@ -46,11 +48,11 @@ pub fn draw_map(map: &HashMap<Coordinate, Creature>) {
             if char != '😀' && x == 0 && y == 0 {
                 char = '⬜';
             }
-            print!("{}", char);
+            line.push(char);
         }
-        println!()
+        lines.push(line);
     }
-    println!()
+    lines.join("\n")
 }
 
 fn get_boundary(map: &HashMap<Coordinate, Creature>) -> Boundary {
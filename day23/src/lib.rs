@@ -2,13 +2,14 @@ mod crater_map;
 mod simulator;
 mod structs;
 
-use crater_map::{count_empty_grounds, draw_map, parse_map};
+use crater_map::{count_empty_grounds, parse_map, render_map};
+use shared::{Metadata, Solution};
 use simulator::simluate_round;
 use structs::Direction;
 
-pub fn process_part1(content: String) -> Option<usize> {
+pub fn process_part1(content: &str) -> Option<usize> {
     let mut map = parse_map(content);
-    draw_map(&map);
+    shared::trace!("\n{}", render_map(&map));
     let mut directions = Direction::default_directions();
     for _ in 0..10 {
         map = simluate_round(&map, &directions).unwrap();
@@ -17,7 +18,7 @@ pub fn process_part1(content: String) -> Option<usize> {
     Some(count_empty_grounds(&map))
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
     let mut map = parse_map(content);
     let mut directions = Direction::default_directions();
     for round in 0.. {
@@ -31,37 +32,34 @@ pub fn process_part2(content: String) -> Option<usize> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 23;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day23;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(110), answer);
-    }
+impl Solution for Day23 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 23,
+        title: "Unstable Diffusion",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(4288), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(20), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(940), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse_map(content))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day23);
+}
@@ -0,0 +1,44 @@
+use crate::process_part2;
+use shared::random::Rng;
+
+/// How much of the valley is blizzards, in percent, for each try. Valleys without a way
+/// there and back are thrown away and the next try has fewer blizzards, down to none at
+/// all, which always has a way.
+const DENSITIES: [usize; 5] = [60, 45, 30, 15, 0];
+
+/// A valley `size` wide inside its walls and a fifth as high, at least 6 by 4, most of it
+/// covered in blizzards. Like the puzzle, no blizzard goes up or down in the columns of
+/// the entrance and the exit, they would blow out of the valley there. Checking for a way
+/// takes as long as solving part 2 again.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    for density in DENSITIES {
+        let content = random_valley(size.max(6), (size / 5).max(4), density, rng);
+        if density == 0 || process_part2(&content).is_some() {
+            return content;
+        }
+    }
+    unreachable!("a valley without blizzards always has a way")
+}
+
+fn random_valley(width: usize, height: usize, density: usize, rng: &mut Rng) -> String {
+    let mut lines = vec![format!("#.{}", "#".repeat(width))];
+    for _ in 0..height {
+        let row = (1..=width)
+            .map(|x| {
+                let directions = if x == 1 || x == width {
+                    &['>', '<'][..]
+                } else {
+                    &['>', '<', '^', 'v'][..]
+                };
+                if rng.chance(density) {
+                    *rng.choose(directions)
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+        lines.push(format!("#{}#", row));
+    }
+    lines.push(format!("{}.#", "#".repeat(width)));
+    lines.join("\n") + "\n"
+}
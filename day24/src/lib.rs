@@ -1,66 +1,71 @@
+mod generator;
 mod path_finder;
 mod wind_maps;
 
 use path_finder::{find_the_shortest_path, State};
+use shared::{random::Rng, Metadata, Solution};
 use wind_maps::{get_outer_size, parse_wind_maps, produce_all_wind_status};
 
-pub fn process_part1(content: String) -> Option<usize> {
+pub fn process_part1(content: &str) -> Option<usize> {
     let wind_maps = parse_wind_maps(content);
     let all_wind_status = produce_all_wind_status(wind_maps);
     let size_info = get_outer_size(&all_wind_status);
     let start_point = (1, 0);
-    let mut start_state = State::new(0, start_point);
-    start_state.set_distance(0);
+    let start_state = State::new(0, start_point);
     let end_point = (size_info.0 - 2, size_info.1 - 1);
-    let end_state = find_the_shortest_path(&all_wind_status, start_state, end_point);
-    end_state.and_then(|state| Some(state.get_distance()))
+    let (_, distance) = find_the_shortest_path(&all_wind_status, start_state, end_point)?;
+    Some(distance)
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
     let wind_maps = parse_wind_maps(content);
     let all_wind_status = produce_all_wind_status(wind_maps);
     let size_info = get_outer_size(&all_wind_status);
     let start_point = (1, 0);
-    let mut start_state = State::new(0, start_point);
-    start_state.set_distance(0);
+    let start_state = State::new(0, start_point);
     let end_point = (size_info.0 - 2, size_info.1 - 1);
-    let end_state = find_the_shortest_path(&all_wind_status, start_state, end_point).unwrap();
-    let end_state = find_the_shortest_path(&all_wind_status, end_state, start_point).unwrap();
-    let end_state = find_the_shortest_path(&all_wind_status, end_state, end_point).unwrap();
-    Some(end_state.get_distance())
+    let mut total_distance = 0;
+    let mut state = start_state;
+    for goal in [end_point, start_point, end_point] {
+        let (end_state, distance) = find_the_shortest_path(&all_wind_status, state, goal)?;
+        total_distance += distance;
+        state = end_state;
+    }
+    Some(total_distance)
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = 24;
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day24;
+
+impl Solution for Day24 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 24,
+        title: "Blizzard Basin",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(18), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(242), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(54), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse_wind_maps(content))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(720), answer);
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day24);
+}
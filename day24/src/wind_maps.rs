@@ -35,7 +35,8 @@ pub fn produce_all_wind_status(mut wind_maps: WindMaps) -> WindMaps {
         wind_maps[3][1..=height].rotate_right(1);
     }
     let mut all_wind_statuses = Vec::new();
-    let repeat_length = shared::lcm(horizontal_wind_statuses.len(), vertical_wind_statuses.len());
+    let repeat_length =
+        shared::lcm(horizontal_wind_statuses.len(), vertical_wind_statuses.len()).unwrap();
     let mut horizontal_wind_statuses = horizontal_wind_statuses.iter().cycle();
     let mut vertical_wind_statuses = vertical_wind_statuses.iter().cycle();
     for _ in 0..repeat_length {
@@ -1,6 +1,6 @@
 use super::{TileType, WindMaps};
 
-pub fn parse_wind_maps(content: String) -> WindMaps {
+pub fn parse_wind_maps(content: &str) -> WindMaps {
     let mut wind_maps = vec![Vec::new(); 4];
     for line in content.lines() {
         let mut wind_rows = vec![Vec::new(); 4];
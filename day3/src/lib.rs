@@ -2,5 +2,57 @@ mod part1;
 mod part2;
 mod structs;
 
-pub use part1::find_share_items;
-pub use part2::find_group_badges;
+use part1::find_share_items;
+use part2::find_group_badges;
+use shared::{Metadata, Solution};
+
+pub fn process_part1(content: &str) -> Option<usize> {
+    let share_items = find_share_items(content);
+    Some(get_sum(&share_items))
+}
+
+pub fn process_part2(content: &str) -> Option<usize> {
+    let badges = find_group_badges(content);
+    Some(get_sum(&badges))
+}
+
+fn get_sum(items: &[char]) -> usize {
+    const LOWERCASE_START: usize = 1;
+    const UPPERCASE_START: usize = 27;
+    items
+        .iter()
+        .map(|c| match c {
+            'a'..='z' => *c as usize - 'a' as usize + LOWERCASE_START,
+            'A'..='Z' => *c as usize - 'A' as usize + UPPERCASE_START,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 3,
+        title: "Rucksack Reorganization",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
+    }
+
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day3);
+}
@@ -1,18 +1,18 @@
 use crate::structs::{Compartment, Group, Rucksack};
 
-pub fn find_group_badges(context: &String) -> Vec<char> {
+pub fn find_group_badges(context: &str) -> Vec<char> {
     let groups = parse_rucksacks_part2(context);
     groups
         .iter()
         .map(|group| {
             group
                 .find_badge()
-                .expect(format!("Can not find badge in {:?}!", group).as_str())
+                .unwrap_or_else(|| panic!("Can not find badge in {:?}!", group))
         })
         .collect()
 }
 
-fn parse_rucksacks_part2(context: &String) -> Vec<Group> {
+fn parse_rucksacks_part2(context: &str) -> Vec<Group> {
     let mut groups = Vec::new();
     let binding = context.lines().collect::<Vec<&str>>();
     let chunks = binding.chunks(3);
@@ -0,0 +1,90 @@
+use shared::{intervals::RangeSet, Metadata, Solution};
+
+#[derive(Debug)]
+struct RangePair {
+    left: RangeSet<usize>,
+    right: RangeSet<usize>,
+}
+
+impl RangePair {
+    fn is_any_fully_contains(&self) -> bool {
+        self.left.is_superset(&self.right) || self.right.is_superset(&self.left)
+    }
+
+    fn is_overlap(&self) -> bool {
+        !self.left.intersection(&self.right).is_empty()
+    }
+}
+
+pub fn process_part1(content: &str) -> Option<usize> {
+    let range_pairs = parse_range_pairs(content);
+    let any_fully_contains_range_pairs = range_pairs
+        .iter()
+        .filter(|range_pair| range_pair.is_any_fully_contains())
+        .count();
+    Some(any_fully_contains_range_pairs)
+}
+
+pub fn process_part2(content: &str) -> Option<usize> {
+    let range_pairs = parse_range_pairs(content);
+    let overlap_range_pairs = range_pairs
+        .iter()
+        .filter(|range_pair| range_pair.is_overlap())
+        .count();
+    Some(overlap_range_pairs)
+}
+
+fn parse_range_pairs(content: &str) -> Vec<RangePair> {
+    content
+        .lines()
+        .map(|line| parse_line(line).unwrap_or_else(|| panic!("Fail to parse line: {}!", line)))
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<RangePair> {
+    let mut binding = line.split(',');
+    let left = binding.next()?;
+    let right = binding.next()?;
+    let left = convert_to_range(left)?;
+    let right = convert_to_range(right)?;
+    Some(RangePair { left, right })
+}
+
+fn convert_to_range(text: &str) -> Option<RangeSet<usize>> {
+    let mut binding = text.split('-');
+    let start = binding.next()?.parse::<usize>().ok()?;
+    let end = binding.next()?.parse::<usize>().ok()?;
+    Some(RangeSet::from(start..=end))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 4,
+        title: "Camp Cleanup",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
+    }
+
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
+    }
+
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse_range_pairs(content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day4);
+}
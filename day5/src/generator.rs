@@ -0,0 +1,46 @@
+use shared::random::Rng;
+
+const STACK_COUNT: usize = 9;
+
+/// Nine stacks, a single digit each like in the puzzle, and `size` moves between them.
+/// The stacks start higher the more moves there are, and no move takes a whole stack so
+/// there is always a top crate. Every stack starts with two, so one always has a spare.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut heights = (0..STACK_COUNT)
+        .map(|_| rng.usize_in(2..=8 + size / 100))
+        .collect::<Vec<usize>>();
+    let max_height = *heights.iter().max().unwrap();
+    let mut lines = Vec::new();
+    for level in (0..max_height).rev() {
+        let cells = heights
+            .iter()
+            .map(|&height| {
+                if level < height {
+                    format!("[{}]", (b'A' + rng.below(26) as u8) as char)
+                } else {
+                    String::from("   ")
+                }
+            })
+            .collect::<Vec<String>>();
+        lines.push(cells.join(" "));
+    }
+    let labels = (1..=STACK_COUNT)
+        .map(|id| format!(" {} ", id))
+        .collect::<Vec<String>>();
+    lines.push(labels.join(" "));
+    lines.push(String::new());
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.below(STACK_COUNT);
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.usize_in(1..=STACK_COUNT - 1)) % STACK_COUNT;
+        let amount = rng.usize_in(1..=heights[from] - 1);
+        heights[from] -= amount;
+        heights[to] += amount;
+        lines.push(format!("move {} from {} to {}", amount, from + 1, to + 1));
+    }
+    lines.join("\n") + "\n"
+}
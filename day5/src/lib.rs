@@ -1,5 +1,7 @@
+mod generator;
+
 use regex::Regex;
-use shared::read_file;
+use shared::{random::Rng, Metadata, Solution};
 
 #[derive(Debug)]
 struct Crate {
@@ -49,15 +51,17 @@ enum CrateMover {
     CM9001,
 }
 
-fn main() {
-    let context = read_file();
-    let stacks = work_with_crate_mover(&context, CrateMover::CM9000);
-    println!("Part 1 answer is: {:?}", get_top_crate_names(stacks));
-    let stacks = work_with_crate_mover(&context, CrateMover::CM9001);
-    println!("Part 2 answer is: {:?}", get_top_crate_names(stacks));
+pub fn process_part1(content: &str) -> Option<String> {
+    let stacks = work_with_crate_mover(content, CrateMover::CM9000);
+    Some(get_top_crate_names(stacks))
+}
+
+pub fn process_part2(content: &str) -> Option<String> {
+    let stacks = work_with_crate_mover(content, CrateMover::CM9001);
+    Some(get_top_crate_names(stacks))
 }
 
-fn work_with_crate_mover(context: &String, crate_mover: CrateMover) -> Vec<Stack> {
+fn work_with_crate_mover(context: &str, crate_mover: CrateMover) -> Vec<Stack> {
     let lines = context.lines().collect::<Vec<&str>>();
     let split_index = lines
         .iter()
@@ -73,7 +77,7 @@ fn work_with_crate_mover(context: &String, crate_mover: CrateMover) -> Vec<Stack
 }
 
 fn parse_stacks(cargo_lines: &[&str]) -> Vec<Stack> {
-    let cargo_lines = cargo_lines.iter().rev().map(|e| *e).collect::<Vec<&str>>();
+    let cargo_lines = cargo_lines.iter().rev().copied().collect::<Vec<&str>>();
     let (stacks_line, cargo_lines) = cargo_lines
         .split_first()
         .expect("Not enough lines in cargo_lines");
@@ -109,7 +113,7 @@ fn parse_moves(move_lines: &[&str]) -> Vec<Move> {
     moves
 }
 
-fn apply_move(crate_mover: &CrateMover, stacks: &mut Vec<Stack>, a_move: &Move) -> Option<()> {
+fn apply_move(crate_mover: &CrateMover, stacks: &mut [Stack], a_move: &Move) -> Option<()> {
     let mut temp = Vec::new();
     {
         let from = stacks.get_mut(a_move.from - 1)?;
@@ -137,3 +141,35 @@ fn get_top_crate_names(stacks: Vec<Stack>) -> String {
         .join("");
     top_crate_names
 }
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 5,
+        title: "Supply Stacks",
+    };
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
+    }
+
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day5);
+}
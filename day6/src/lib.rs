@@ -1,12 +1,14 @@
-pub fn process_part1(content: String) -> Option<usize> {
+use shared::{Metadata, Solution};
+
+pub fn process_part1(content: &str) -> Option<usize> {
     find_signal_with_length(content, 4)
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
     find_signal_with_length(content, 14)
 }
 
-fn find_signal_with_length(content: String, length: usize) -> Option<usize> {
+fn find_signal_with_length(content: &str, length: usize) -> Option<usize> {
     for i in 0..(content.len() - length) {
         let chars = content.chars().skip(i).take(length).collect::<Vec<char>>();
         let mut is_all_uniq = true;
@@ -22,44 +24,31 @@ fn find_signal_with_length(content: String, length: usize) -> Option<usize> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day6;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(6);
-        let expects = vec![Some(7), Some(5), Some(6), Some(10), Some(11)];
-        let answers = content
-            .lines()
-            .map(|line| process_part1(line.into()))
-            .collect::<Vec<Option<usize>>>();
-        assert_eq!(expects, answers);
-    }
+impl Solution for Day6 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 6,
+        title: "Tuning Trouble",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(6);
-        let answer = process_part1(content);
-        assert_eq!(Some(1965), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(6);
-        let expects = vec![Some(19), Some(23), Some(23), Some(29), Some(26)];
-        let answers = content
-            .lines()
-            .map(|line| process_part2(line.into()))
-            .collect::<Vec<Option<usize>>>();
-        assert_eq!(expects, answers);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
+}
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(6);
-        let answer = process_part2(content);
-        assert_eq!(Some(2773), answer);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day6);
+    shared::answer_tests!(Day6, Samples);
 }
@@ -0,0 +1,90 @@
+use shared::random::Rng;
+use std::collections::HashSet;
+
+const MAX_DEPTH: usize = 10;
+const EXTENSIONS: [&str; 5] = ["", "", ".txt", ".dat", ".log"];
+
+#[derive(Default)]
+struct Dir {
+    names: HashSet<String>,
+    dirs: Vec<(String, usize)>,
+    files: Vec<(String, usize)>,
+}
+
+fn new_name(rng: &mut Rng, dir: &mut Dir, extension: &str) -> String {
+    loop {
+        let name = (0..rng.usize_in(1..=8))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect::<String>()
+            + extension;
+        if dir.names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// A transcript that explores every directory once, with `size` files in about a quarter
+/// as many directories. The files add up to between the 40000000 part 2 has to leave and
+/// the 70000000 of the disk.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut dirs = vec![Dir::default()];
+    let mut depths = vec![0];
+    for index in 1..size.div_ceil(4).max(1) {
+        let parent = loop {
+            let parent = rng.below(index);
+            if depths[parent] < MAX_DEPTH {
+                break parent;
+            }
+        };
+        let name = new_name(rng, &mut dirs[parent], "");
+        dirs[parent].dirs.push((name, index));
+        dirs.push(Dir::default());
+        depths.push(depths[parent] + 1);
+    }
+    let weights = (0..size.max(1))
+        .map(|_| rng.usize_in(1..=1000))
+        .collect::<Vec<usize>>();
+    let total = rng.usize_in(41_000_000..=69_000_000);
+    let weight_sum = weights.iter().sum::<usize>();
+    let mut file_sizes = weights
+        .iter()
+        .map(|weight| (total * weight / weight_sum).max(1))
+        .collect::<Vec<usize>>();
+    // Rounding down loses up to a byte per file, the last one gets them back.
+    let lost = total.saturating_sub(file_sizes.iter().sum());
+    *file_sizes.last_mut().unwrap() += lost;
+    for file_size in file_sizes {
+        let index = rng.below(dirs.len());
+        let extension = *rng.choose(&EXTENSIONS);
+        let name = new_name(rng, &mut dirs[index], extension);
+        dirs[index].files.push((name, file_size));
+    }
+    let mut lines = vec![String::from("$ cd /")];
+    list(&dirs, 0, rng, &mut lines);
+    while lines.last().is_some_and(|line| line == "$ cd ..") {
+        lines.pop();
+    }
+    lines.join("\n") + "\n"
+}
+
+fn list(dirs: &[Dir], index: usize, rng: &mut Rng, lines: &mut Vec<String>) {
+    let dir = &dirs[index];
+    let mut entries = dir
+        .dirs
+        .iter()
+        .map(|(name, _)| format!("dir {}", name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut entries);
+    lines.push(String::from("$ ls"));
+    lines.extend(entries);
+    for (name, child) in dir.dirs.iter() {
+        lines.push(format!("$ cd {}", name));
+        list(dirs, *child, rng, lines);
+        lines.push(String::from("$ cd .."));
+    }
+}
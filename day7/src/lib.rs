@@ -1,8 +1,10 @@
+mod generator;
 mod structs;
 
+use shared::{random::Rng, Metadata, Solution};
 use structs::*;
 
-pub fn process_part1(content: String) -> Option<usize> {
+pub fn process_part1(content: &str) -> Option<usize> {
     let shell = parse_as_shell(content);
     Some(
         shell
@@ -13,7 +15,7 @@ pub fn process_part1(content: String) -> Option<usize> {
     )
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
     const TOTAL_SIZE: usize = 70000000;
     const SIZE_NEED: usize = 30000000;
     let shell = parse_as_shell(content);
@@ -25,7 +27,7 @@ pub fn process_part2(content: String) -> Option<usize> {
     Some(size)
 }
 
-fn parse_as_shell(content: String) -> Shell {
+fn parse_as_shell(content: &str) -> Shell {
     let flat_commands = FlatCommands::parse(content);
     let commands = flat_commands.compact();
     let mut shell = Shell::new();
@@ -35,36 +37,38 @@ fn parse_as_shell(content: String) -> Shell {
     shell
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day7;
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(7);
-        let answer = process_part1(content);
-        assert_eq!(Some(95437), answer);
+impl Solution for Day7 {
+    const METADATA: Metadata = Metadata {
+        year: 2022,
+        day: 7,
+        title: "No Space Left On Device",
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(7);
-        let answer = process_part1(content);
-        assert_eq!(Some(1555642), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(7);
-        let answer = process_part2(content);
-        assert_eq!(Some(24933642), answer);
+    fn parse(content: &str, _: &Self::Params) -> Option<impl Sized> {
+        Some(parse_as_shell(content))
     }
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(7);
-        let answer = process_part2(content);
-        assert_eq!(Some(5974547), answer);
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generator::generate(size, rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day7);
+}
@@ -230,7 +230,7 @@ pub struct FlatCommands {
 }
 
 impl FlatCommands {
-    pub fn parse(content: String) -> Self {
+    pub fn parse(content: &str) -> Self {
         let mut commands = Vec::new();
         let command_pattern = Regex::new(r"^\$ (\w+)\s?(\w+|/|\.\.)?").unwrap();
         for line in content.lines() {
@@ -0,0 +1,3 @@
+fn main() {
+    shared::ignore_missing_input();
+}
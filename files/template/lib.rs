@@ -1,42 +1,43 @@
-pub fn process_part1(content: String) -> Option<usize> {
+mod parser;
+mod structs;
+
+use parser::parse_lines;
+use shared::{Metadata, Solution};
+
+pub fn process_part1(content: &str) -> Option<usize> {
+    let _lines = parse_lines(content);
     None
 }
 
-pub fn process_part2(content: String) -> Option<usize> {
+pub fn process_part2(content: &str) -> Option<usize> {
+    let _lines = parse_lines(content);
     None
 }
 
-#[cfg(test)]
-mod tests {
-    const DAY_NUMBER: usize = ();
-    use super::*;
-    use shared::{read_input, read_sample};
+pub struct Day{{day}};
 
-    #[test]
-    fn process_part1_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(0), answer);
-    }
+impl Solution for Day{{day}} {
+    const METADATA: Metadata = Metadata {
+        year: {{year}},
+        day: {{day}},
+        title: {{title}},
+    };
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    #[test]
-    fn process_part1_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part1(content);
-        assert_eq!(Some(0), answer);
+    fn part1(content: &str, _: &Self::Params) -> Option<Self::Answer1> {
+        process_part1(content)
     }
 
-    #[test]
-    fn process_part2_with_sample() {
-        let content = read_sample(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(0), answer);
+    fn part2(content: &str, _: &Self::Params) -> Option<Self::Answer2> {
+        process_part2(content)
     }
+}
 
-    #[test]
-    fn process_part2_with_input() {
-        let content = read_input(DAY_NUMBER);
-        let answer = process_part2(content);
-        assert_eq!(Some(0), answer);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    shared::answer_tests!(Day{{day}});
 }
@@ -0,0 +1,3 @@
+pub fn parse_lines(content: &str) -> Vec<&str> {
+    content.lines().collect()
+}
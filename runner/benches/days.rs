@@ -0,0 +1,46 @@
+use runner::{
+    bench::{measure, Report},
+    days,
+};
+use shared::{progress, workspace_root, InputKind, Part};
+use std::{env, fs};
+
+/// `cargo bench -p runner -- 16 19` only measures the given days, all of them by default.
+/// A day is picked in every year it exists in.
+fn main() {
+    // A bar drawn while measuring would be measured too.
+    progress::set_quiet(true);
+    let selected = env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse::<usize>().ok())
+        .collect::<Vec<usize>>();
+    let mut report = Report::default();
+    for day in days()
+        .into_iter()
+        .filter(|day| selected.is_empty() || selected.contains(&day.metadata.day))
+    {
+        for input_kind in [InputKind::Sample, InputKind::Input] {
+            for part in Part::all() {
+                match measure(&day, part, input_kind) {
+                    Ok(measurement) => report.push(measurement),
+                    Err(err) => {
+                        eprintln!("skipped {} part{}: {}", day.metadata.puzzle(), part, err)
+                    }
+                }
+            }
+        }
+    }
+    let root = workspace_root().unwrap_or_else(|err| panic!("{}", err));
+    let report_path = root.join("target").join("aoc-bench.json");
+    let previous = fs::read_to_string(&report_path)
+        .ok()
+        .and_then(|json| Report::from_json(&json).ok());
+    println!("{}", report.table(previous.as_ref()));
+    let merged = match previous {
+        Some(previous) => report.merge_into(&previous),
+        None => report,
+    };
+    fs::create_dir_all(report_path.parent().unwrap()).unwrap();
+    fs::write(&report_path, merged.to_json()).unwrap();
+    println!("Report written to {}", report_path.display());
+}
@@ -0,0 +1,218 @@
+use crate::Day;
+use serde::{Deserialize, Serialize};
+use shared::{
+    file_path, parse::in_input_file, try_read_kind, InputError, InputKind, Part, DEFAULT_YEAR,
+};
+use std::time::{Duration, Instant};
+
+/// A part is solved again until it took this long in total, or ran `MAX_RUNS` times.
+const TIME_BUDGET: Duration = Duration::from_secs(1);
+const MAX_RUNS: u32 = 10;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Measurement {
+    /// Missing in reports from before there were other years than 2022.
+    #[serde(default = "default_year")]
+    pub year: usize,
+    pub day: usize,
+    pub part: String,
+    pub input: String,
+    pub answer: Option<String>,
+    pub load_nanos: u64,
+    /// Median of all the runs, missing for days which parse along the way of solving and
+    /// in reports from before parsing was timed.
+    #[serde(default)]
+    pub parse_nanos: Option<u64>,
+    /// Median of all the runs.
+    pub solve_nanos: u64,
+    pub runs: u32,
+}
+
+impl Measurement {
+    fn is_same_case(&self, other: &Measurement) -> bool {
+        self.year == other.year
+            && self.day == other.day
+            && self.part == other.part
+            && self.input == other.input
+    }
+}
+
+fn default_year() -> usize {
+    DEFAULT_YEAR
+}
+
+/// Loads the file of `input_kind` and times how long `part` of the day takes to parse and
+/// to solve it. Reading the parameters is part of loading. Every `Solution` takes the raw
+/// content, so solving parses it again.
+pub fn measure(day: &Day, part: Part, input_kind: InputKind) -> Result<Measurement, InputError> {
+    let start = Instant::now();
+    let content = try_read_kind(day.metadata.puzzle(), input_kind)?;
+    let solver = day.solver(input_kind)?;
+    let load = start.elapsed();
+    let mut answer = None;
+    let mut parse_times = Vec::new();
+    let mut times = Vec::new();
+    in_input_file(file_path(day.metadata.puzzle(), input_kind)?, || {
+        while times.len() < MAX_RUNS as usize && times.iter().sum::<Duration>() < TIME_BUDGET {
+            let start = Instant::now();
+            if solver.parse(&content) {
+                parse_times.push(start.elapsed());
+            }
+            let start = Instant::now();
+            answer = solver.solve(part, &content);
+            times.push(start.elapsed());
+        }
+    });
+    let runs = times.len() as u32;
+    Ok(Measurement {
+        year: day.metadata.year,
+        day: day.metadata.day,
+        part: part.to_string(),
+        input: input_kind.name().to_string(),
+        answer,
+        load_nanos: load.as_nanos() as u64,
+        parse_nanos: median(parse_times).map(|parse| parse.as_nanos() as u64),
+        solve_nanos: median(times).unwrap_or_default().as_nanos() as u64,
+        runs,
+    })
+}
+
+fn median(mut times: Vec<Duration>) -> Option<Duration> {
+    times.sort();
+    times.get(times.len() / 2).copied()
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn push(&mut self, measurement: Measurement) {
+        self.measurements.push(measurement);
+    }
+
+    /// Keeps the measurements of `previous` which were not measured again this time,
+    /// so benchmarking a few days does not throw the others away.
+    pub fn merge_into(&self, previous: &Report) -> Report {
+        let mut measurements = previous.measurements.clone();
+        for new in self.measurements.iter() {
+            match measurements.iter_mut().find(|old| old.is_same_case(new)) {
+                Some(old) => *old = new.clone(),
+                None => measurements.push(new.clone()),
+            }
+        }
+        Report { measurements }
+    }
+
+    pub fn table(&self, previous: Option<&Report>) -> String {
+        let mut lines = vec![
+            String::from(
+                "| year | day | input  | part |       load |      parse |      solve | runs | change  |",
+            ),
+            String::from(
+                "|-----:|----:|--------|-----:|-----------:|-----------:|-----------:|-----:|--------:|",
+            ),
+        ];
+        for measurement in self.measurements.iter() {
+            let change = previous
+                .and_then(|previous| {
+                    previous
+                        .measurements
+                        .iter()
+                        .find(|old| old.is_same_case(measurement))
+                })
+                .filter(|old| old.solve_nanos > 0)
+                .map(|old| {
+                    let ratio = measurement.solve_nanos as f64 / old.solve_nanos as f64;
+                    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+                })
+                .unwrap_or_default();
+            lines.push(format!(
+                "| {:>4} | {:>3} | {:<6} | {:>4} | {:>10} | {:>10} | {:>10} | {:>4} | {:>7} |",
+                measurement.year,
+                measurement.day,
+                measurement.input,
+                measurement.part,
+                format_nanos(measurement.load_nanos),
+                measurement
+                    .parse_nanos
+                    .map(format_nanos)
+                    .unwrap_or_else(|| String::from("-")),
+                format_nanos(measurement.solve_nanos),
+                measurement.runs,
+                change
+            ));
+        }
+        lines.join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn from_json(json: &str) -> Result<Report, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+fn format_nanos(nanos: u64) -> String {
+    let duration = Duration::from_nanos(nanos);
+    if duration >= Duration::from_secs(1) {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else if duration >= Duration::from_millis(1) {
+        format!("{:.2}ms", duration.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2}µs", duration.as_secs_f64() * 1e6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_day;
+
+    #[test]
+    fn test_measure() {
+        let day = find_day(1).unwrap();
+        let measurement = measure(&day, Part::One, InputKind::Sample).unwrap();
+        assert_eq!(Some(String::from("24000")), measurement.answer);
+        assert_eq!(MAX_RUNS, measurement.runs);
+        assert!(measurement.parse_nanos.is_some());
+        let day = find_day(6).unwrap();
+        let measurement = measure(&day, Part::One, InputKind::Sample).unwrap();
+        assert_eq!(None, measurement.parse_nanos);
+    }
+
+    #[test]
+    fn test_report_json_and_merge() {
+        let measurement = |day, solve_nanos| Measurement {
+            year: DEFAULT_YEAR,
+            day,
+            part: String::from("1"),
+            input: String::from("sample"),
+            answer: None,
+            load_nanos: 0,
+            parse_nanos: None,
+            solve_nanos,
+            runs: 1,
+        };
+        let previous = Report {
+            measurements: vec![measurement(1, 100), measurement(2, 100)],
+        };
+        let report = Report {
+            measurements: vec![measurement(2, 150)],
+        };
+        let merged = report.merge_into(&previous);
+        assert_eq!(
+            vec![measurement(1, 100), measurement(2, 150)],
+            merged.measurements
+        );
+        assert_eq!(merged, Report::from_json(&merged.to_json()).unwrap());
+        assert!(report.table(Some(&previous)).contains("+50.0%"));
+        let old_json = r#"{"measurements": [{"day": 1, "part": "1", "input": "sample",
+            "answer": null, "load_nanos": 0, "solve_nanos": 100, "runs": 1}]}"#;
+        let old = Report::from_json(old_json).unwrap();
+        assert_eq!(vec![measurement(1, 100)], old.measurements);
+    }
+}
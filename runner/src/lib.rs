@@ -0,0 +1,175 @@
+pub mod bench;
+
+use shared::{random::Rng, InputError, InputKind, Metadata, Part, Puzzle, Solution};
+use std::{hint::black_box, rc::Rc};
+
+type LoadFn = fn(InputKind) -> Result<Solver, InputError>;
+type SolveFn = Box<dyn Fn(Part, &str) -> Option<String>>;
+type ParseFn = Box<dyn Fn(&str) -> bool>;
+type GenerateFn = fn(usize, &mut Rng) -> Option<String>;
+
+/// A `Solution` with its types erased, so every day fits in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub metadata: Metadata,
+    load_fn: LoadFn,
+    generate_fn: GenerateFn,
+}
+
+impl Day {
+    fn of<S: Solution + 'static>() -> Self {
+        Self {
+            metadata: S::METADATA,
+            load_fn: load::<S>,
+            generate_fn: S::generate,
+        }
+    }
+
+    /// Reads the parameters for `input_kind` once, to solve any amount of parts with.
+    pub fn solver(&self, input_kind: InputKind) -> Result<Solver, InputError> {
+        (self.load_fn)(input_kind)
+    }
+
+    pub fn solve(
+        &self,
+        part: Part,
+        content: &str,
+        input_kind: InputKind,
+    ) -> Result<Option<String>, InputError> {
+        Ok(self.solver(input_kind)?.solve(part, content))
+    }
+
+    /// A random input of `size` from `seed`, the same one every time for them. `None`
+    /// when the day has no generator.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        (self.generate_fn)(size, &mut Rng::new(seed))
+    }
+}
+
+/// A day with its parameters read.
+pub struct Solver {
+    solve_fn: SolveFn,
+    parse_fn: ParseFn,
+}
+
+impl Solver {
+    pub fn solve(&self, part: Part, content: &str) -> Option<String> {
+        (self.solve_fn)(part, content)
+    }
+
+    /// Only parses `content`, `false` when the day parses along the way of solving.
+    pub fn parse(&self, content: &str) -> bool {
+        (self.parse_fn)(content)
+    }
+}
+
+fn load<S: Solution + 'static>(input_kind: InputKind) -> Result<Solver, InputError> {
+    let params = Rc::new(S::params(input_kind)?);
+    let parse_params = Rc::clone(&params);
+    Ok(Solver {
+        solve_fn: Box::new(move |part, content| S::solve(part, content, &params)),
+        parse_fn: Box::new(move |content| black_box(S::parse(content, &parse_params)).is_some()),
+    })
+}
+
+/// Every day of every year, ordered by year and then by day.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<day1::Day1>(),
+        Day::of::<day2::Day2>(),
+        Day::of::<day3::Day3>(),
+        Day::of::<day4::Day4>(),
+        Day::of::<day5::Day5>(),
+        Day::of::<day6::Day6>(),
+        Day::of::<day7::Day7>(),
+        Day::of::<day8::Day8>(),
+        Day::of::<day9::Day9>(),
+        Day::of::<day10::Day10>(),
+        Day::of::<day11::Day11>(),
+        Day::of::<day12::Day12>(),
+        Day::of::<day13::Day13>(),
+        Day::of::<day14::Day14>(),
+        Day::of::<day15::Day15>(),
+        Day::of::<day16::Day16>(),
+        Day::of::<day17::Day17>(),
+        Day::of::<day18::Day18>(),
+        Day::of::<day19::Day19>(),
+        Day::of::<day20::Day20>(),
+        Day::of::<day21::Day21>(),
+        Day::of::<day22::Day22>(),
+        Day::of::<day23::Day23>(),
+        Day::of::<day24::Day24>(),
+    ]
+}
+
+pub fn find_day(puzzle: impl Into<Puzzle>) -> Result<Day, String> {
+    let puzzle = puzzle.into();
+    days()
+        .into_iter()
+        .find(|found| found.metadata.puzzle() == puzzle)
+        .ok_or_else(|| format!("The {} is not supported by the runner!", puzzle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let day = find_day(6).unwrap();
+        let answer = day.solve(
+            Part::One,
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            InputKind::Sample,
+        );
+        assert_eq!(Some(String::from("7")), answer.unwrap());
+    }
+
+    #[test]
+    fn test_solver_params() {
+        let content = shared::read_sample(22);
+        let solver = find_day(22).unwrap().solver(InputKind::Sample).unwrap();
+        assert_eq!(
+            Some(String::from("5031")),
+            solver.solve(Part::Two, &content)
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        for day in days() {
+            let Some(content) = day.generate(4, 2022) else {
+                continue;
+            };
+            assert_eq!(Some(&content), day.generate(4, 2022).as_ref());
+            assert_ne!(Some(&content), day.generate(4, 2023).as_ref());
+            // The search of day 19 takes seconds for every cheap blueprint.
+            if day.metadata.day == 19 {
+                continue;
+            }
+            let solver = day.solver(InputKind::Input).unwrap();
+            for part in Part::all() {
+                let answer = solver.solve(part, &content);
+                assert!(answer.is_some(), "{} has no answer for:\n{}", part, content);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_day_unknown() {
+        assert!(find_day(42).is_err());
+        assert!(find_day(Puzzle::new(2015, 1)).is_err());
+    }
+
+    #[test]
+    fn test_days_in_order() {
+        let puzzles = days()
+            .iter()
+            .map(|day| day.metadata.puzzle())
+            .collect::<Vec<Puzzle>>();
+        let mut sorted = puzzles.clone();
+        sorted.sort();
+        assert_eq!(sorted, puzzles);
+        assert!(puzzles.contains(&Puzzle::new(2022, 24)));
+    }
+}
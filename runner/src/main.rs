@@ -0,0 +1,141 @@
+use bpaf::Bpaf;
+use runner::find_day;
+use shared::{
+    file_path, parse::in_input_file, progress, try_read_file, InputKind, Part, Puzzle, DEFAULT_YEAR,
+};
+use std::{
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Debug, Bpaf)]
+#[bpaf(options)]
+enum Options {
+    /// Run the solution of a day and print the answers
+    #[bpaf(command)]
+    Run {
+        /// Only run part 1 or part 2
+        #[bpaf(short, long, argument("PART"))]
+        part: Option<usize>,
+        /// Use files/YEAR/dayN/sample.txt and the sample parameters
+        #[bpaf(short, long)]
+        sample: bool,
+        /// Puzzle input to use instead of files/YEAR/dayN/input.txt, - reads stdin
+        #[bpaf(short, long, argument("PATH"))]
+        input: Option<PathBuf>,
+        /// Year of the puzzle, 2022 by default
+        #[bpaf(short, long, argument("YEAR"))]
+        year: Option<usize>,
+        /// Hide the progress bars of the long searches
+        #[bpaf(short, long)]
+        quiet: bool,
+        /// Give up on a long search after SECONDS, its part has no answer then
+        #[bpaf(short, long, argument("SECONDS"))]
+        timeout: Option<f64>,
+        /// Day number, from 1 to 25
+        #[bpaf(positional("DAY"))]
+        day: usize,
+    },
+    /// Print a random input of a day, the same one for the same size and seed
+    #[bpaf(command)]
+    Generate {
+        /// How big the input is, in a unit that depends on the day, 10 by default
+        #[bpaf(short('n'), long, argument("SIZE"))]
+        size: Option<usize>,
+        /// Seed of the random numbers, 0 by default
+        #[bpaf(short, long, argument("SEED"))]
+        seed: Option<u64>,
+        /// Year of the puzzle, 2022 by default
+        #[bpaf(short, long, argument("YEAR"))]
+        year: Option<usize>,
+        /// Day number, from 1 to 25
+        #[bpaf(positional("DAY"))]
+        day: usize,
+    },
+}
+
+fn main() {
+    let options = options().run();
+    match options {
+        Options::Run {
+            part,
+            sample,
+            input,
+            year,
+            quiet,
+            timeout,
+            day,
+        } => {
+            if quiet {
+                progress::set_quiet(true);
+            }
+            if let Some(seconds) = timeout {
+                let timeout = Duration::try_from_secs_f64(seconds)
+                    .unwrap_or_else(|err| exit_with(&format!("Bad timeout {}({})", seconds, err)));
+                progress::set_deadline(Some(Instant::now() + timeout));
+            }
+            run(
+                Puzzle::new(year.unwrap_or(DEFAULT_YEAR), day),
+                part,
+                sample,
+                input,
+            )
+        }
+        Options::Generate {
+            size,
+            seed,
+            year,
+            day,
+        } => generate(
+            Puzzle::new(year.unwrap_or(DEFAULT_YEAR), day),
+            size.unwrap_or(10),
+            seed.unwrap_or(0),
+        ),
+    }
+}
+
+fn run(puzzle: Puzzle, part: Option<usize>, sample: bool, input: Option<PathBuf>) {
+    let solution = find_day(puzzle).unwrap_or_else(|err| exit_with(&err));
+    let parts = match part {
+        Some(number) => vec![Part::try_from(number).unwrap_or_else(|err| exit_with(&err))],
+        None => Part::all(),
+    };
+    let input_kind = if sample {
+        InputKind::Sample
+    } else {
+        InputKind::Input
+    };
+    let input = match input {
+        Some(input) => input,
+        None => file_path(puzzle, input_kind).unwrap_or_else(|err| exit_with(&err.to_string())),
+    };
+    let content = try_read_file(&input).unwrap_or_else(|err| exit_with(&err.to_string()));
+    println!(
+        "Day {} of {}: {}",
+        puzzle.day, puzzle.year, solution.metadata.title
+    );
+    let solver = solution
+        .solver(input_kind)
+        .unwrap_or_else(|err| exit_with(&err.to_string()));
+    for part in parts {
+        match in_input_file(&input, || solver.solve(part, &content)) {
+            Some(answer) if answer.contains('\n') => println!("Part {}:\n{}", part, answer),
+            Some(answer) => println!("Part {}: {}", part, answer),
+            None => println!("Part {}: no answer", part),
+        }
+    }
+}
+
+fn generate(puzzle: Puzzle, size: usize, seed: u64) {
+    let solution = find_day(puzzle).unwrap_or_else(|err| exit_with(&err));
+    match solution.generate(size, seed) {
+        Some(content) => print!("{}", content),
+        None => exit_with(&format!("The {} has no input generator!", puzzle)),
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
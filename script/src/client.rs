@@ -0,0 +1,305 @@
+use crate::days::{write, Change};
+use shared::{Part, Puzzle};
+use std::{env, fmt::Display, fs, path::Path, time::Duration};
+
+/// The `session` cookie of a logged in adventofcode.com account.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Where to fetch from and submit to instead of `DEFAULT_BASE_URL`, like a mock server in tests.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Asked for by the puzzle author, so they can reach out when a tool misbehaves.
+const USER_AGENT: &str = concat!(
+    "github.com/MickPlaYer/aoc2022 script/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Answered too recently, submit again after this long.
+    TooRecent(Duration),
+    /// The part is already solved, or part 1 is not yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Verdicts about the answer itself, the others say nothing about it.
+    pub fn is_judgement(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Right | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+
+    /// Reads the `<article>` of the page the server answers a submission with.
+    pub fn parse(page: &str) -> Verdict {
+        let article = page
+            .split("<article>")
+            .nth(1)
+            .and_then(|article| article.split("</article>").next())
+            .unwrap_or(page);
+        if article.contains("That's the right answer") {
+            Verdict::Right
+        } else if article.contains("That's not the right answer") {
+            if article.contains("too high") {
+                Verdict::TooHigh
+            } else if article.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if article.contains("You gave an answer too recently") {
+            Verdict::TooRecent(parse_wait(article).unwrap_or_default())
+        } else if article.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(strip_tags(article))
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::TooRecent(wait) => write!(f, "too recent, wait {}s", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "not for the level you are on"),
+            Verdict::Unknown(text) => write!(f, "unknown ({})", text),
+        }
+    }
+}
+
+/// Reads `You have 1m 23s left to wait`.
+fn parse_wait(article: &str) -> Option<Duration> {
+    let wait = article.split("You have ").nth(1)?.split(" left").next()?;
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let (number, unit) = amount.split_at(amount.len() - 1);
+        let number = number.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let session = env::var(SESSION_ENV).map_err(|_| {
+            format!(
+                "Set {} to the session cookie of adventofcode.com!",
+                SESSION_ENV
+            )
+        })?;
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Downloads the puzzle input into `files/YEAR/dayN/input.txt`. A file already there
+    /// is the cache and never downloaded again, only an empty one left by `--new-day` is.
+    pub fn fetch_input(&self, root: &Path, puzzle: Puzzle) -> Result<Change, String> {
+        let path = root
+            .join("files")
+            .join(puzzle.year.to_string())
+            .join(format!("day{}", puzzle.day))
+            .join("input.txt");
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Change::Unchanged(path));
+        }
+        let url = self.puzzle_url(puzzle, "input");
+        let response = self.request("GET", &url).call();
+        let content = read_response(&url, response)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Can not create dir {}({})", dir.display(), err))?;
+        }
+        let existed = path.exists();
+        write(&path, &content)?;
+        if existed {
+            Ok(Change::Updated(path))
+        } else {
+            Ok(Change::Created(path))
+        }
+    }
+
+    pub fn submit_answer(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict, String> {
+        let url = self.puzzle_url(puzzle, "answer");
+        let level = part.to_string();
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)]);
+        let page = read_response(&url, response)?;
+        Ok(Verdict::parse(&page))
+    }
+
+    fn puzzle_url(&self, puzzle: Puzzle, page: &str) -> String {
+        format!(
+            "{}/{}/day/{}/{}",
+            self.base_url, puzzle.year, puzzle.day, page
+        )
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, String> {
+    let response = response.map_err(|err| match err {
+        ureq::Error::Status(status, _) => {
+            format!(
+                "{} answered {}, is {} still valid?",
+                url, status, SESSION_ENV
+            )
+        }
+        ureq::Error::Transport(transport) => format!("Can not reach {}({})", url, transport),
+    })?;
+    response
+        .into_string()
+        .map_err(|err| format!("Can not read the answer of {}({})", url, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::mock_server;
+    use std::path::PathBuf;
+
+    fn empty_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("files/2022/day3")).unwrap();
+        fs::write(root.join("files/2022/day3/input.txt"), "").unwrap();
+        root
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let root = empty_root("aoc-script-test-fetch");
+        let (base_url, requests) = mock_server("200 OK", "vJrwpWtwJgWr\n");
+        let fetcher = Client::new(&base_url, "secret\n");
+        let path = root.join("files/2022/day3/input.txt");
+        assert_eq!(
+            Ok(Change::Updated(path.clone())),
+            fetcher.fetch_input(&root, Puzzle::from(3))
+        );
+        assert_eq!("vJrwpWtwJgWr\n", fs::read_to_string(&path).unwrap());
+        let head = requests.recv().unwrap().to_lowercase();
+        assert!(head.starts_with("get /2022/day/3/input http/1.1"));
+        assert!(head.contains("cookie: session=secret\r\n"));
+        assert!(head.contains("user-agent: github.com/mickplayer/aoc2022"));
+        // The mock server is gone, so this only works from the cache.
+        assert_eq!(
+            Ok(Change::Unchanged(path)),
+            fetcher.fetch_input(&root, Puzzle::from(3))
+        );
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let root = empty_root("aoc-script-test-fetch-error");
+        let (base_url, _) = mock_server("400 Bad Request", "Please log in.");
+        let fetcher = Client::new(&base_url, "expired");
+        let err = fetcher.fetch_input(&root, Puzzle::from(3)).unwrap_err();
+        assert!(err.contains("answered 400"), "{}", err);
+        let content = fs::read_to_string(root.join("files/2022/day3/input.txt")).unwrap();
+        assert_eq!("", content);
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let page = "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>";
+        let (base_url, requests) = mock_server("200 OK", page);
+        let client = Client::new(&base_url, "secret");
+        let verdict = client
+            .submit_answer(Puzzle::new(2021, 3), Part::Two, "70")
+            .unwrap();
+        assert_eq!(Verdict::TooHigh, verdict);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2021/day/3/answer HTTP/1.1"));
+        assert!(request.ends_with("\r\nlevel=2&answer=70"));
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let article = |text: &str| format!("<html><article><p>{}</p></article></html>", text);
+        let parse = |text: &str| Verdict::parse(&article(text));
+        assert_eq!(
+            Verdict::Right,
+            parse("That's the right answer! You are one gold star closer.")
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            parse("That's not the right answer. If you're stuck...")
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            parse("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            Verdict::TooRecent(Duration::from_secs(83)),
+            parse("You gave an answer too recently. You have 1m 23s left to wait.")
+        );
+        assert_eq!(
+            Verdict::WrongLevel,
+            parse("You don't seem to be solving the right level. Did you already complete it?")
+        );
+        assert_eq!(
+            Verdict::Unknown(String::from("Hello")),
+            parse("<b>Hello</b>")
+        );
+    }
+}
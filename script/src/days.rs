@@ -0,0 +1,465 @@
+use crate::manifest::{
+    add_answer_tables, add_member, add_path_dependency, add_version_dependency, edit_toml,
+    remove_dependency, remove_member,
+};
+use shared::{Puzzle, DEFAULT_YEAR};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE_DIR: &str = "files/template";
+/// Template files and where they go in the new crate.
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml.template", "Cargo.toml"),
+    ("build.rs", "build.rs"),
+    ("lib.rs", "src/lib.rs"),
+    ("parser.rs", "src/parser.rs"),
+    ("structs.rs", "src/structs.rs"),
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Dependency {
+    Itertools,
+    Nom,
+    TestCase,
+}
+
+impl Dependency {
+    fn name(&self) -> &'static str {
+        match self {
+            Dependency::Itertools => "itertools",
+            Dependency::Nom => "nom",
+            Dependency::TestCase => "test-case",
+        }
+    }
+
+    /// Same versions as the other days use.
+    fn version(&self) -> &'static str {
+        match self {
+            Dependency::Itertools => "0.10.5",
+            Dependency::Nom => "7.1.1",
+            Dependency::TestCase => "2.2.2",
+        }
+    }
+}
+
+/// Where the crate of a puzzle lives. Days of `DEFAULT_YEAR` keep their `dayN` crates at
+/// the root, the other years get `aocYEAR/dayN` crates named `aocYEAR-dayN`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct DayCrate {
+    puzzle: Puzzle,
+}
+
+impl DayCrate {
+    fn new(puzzle: Puzzle) -> Self {
+        Self { puzzle }
+    }
+
+    /// Package name, also the key of its dependency in the runner.
+    fn name(&self) -> String {
+        if self.puzzle.year == DEFAULT_YEAR {
+            format!("day{}", self.puzzle.day)
+        } else {
+            format!("aoc{}-day{}", self.puzzle.year, self.puzzle.day)
+        }
+    }
+
+    /// Path from the workspace root, also its workspace member.
+    fn dir(&self) -> String {
+        if self.puzzle.year == DEFAULT_YEAR {
+            format!("day{}", self.puzzle.day)
+        } else {
+            format!("aoc{}/day{}", self.puzzle.year, self.puzzle.day)
+        }
+    }
+
+    fn lib_name(&self) -> String {
+        self.name().replace('-', "_")
+    }
+
+    /// Relative path from the crate back to the workspace root.
+    fn root(&self) -> String {
+        "../".repeat(self.dir().matches('/').count() + 1)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NewDay {
+    pub puzzle: Puzzle,
+    pub title: String,
+    pub dependencies: Vec<Dependency>,
+}
+
+impl NewDay {
+    fn fill_template(&self, template: &str) -> String {
+        let day_crate = DayCrate::new(self.puzzle);
+        template
+            .replace("{{crate}}", &day_crate.name())
+            .replace("{{root}}", &day_crate.root())
+            .replace("{{year}}", &self.puzzle.year.to_string())
+            .replace("{{day}}", &self.puzzle.day.to_string())
+            // Debug quotes and escapes the title into a valid string literal.
+            .replace("{{title}}", &format!("{:?}", self.title))
+    }
+}
+
+/// What happened to one of the files a day needs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    Created(PathBuf),
+    Updated(PathBuf),
+    Unchanged(PathBuf),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Created(path) => write!(f, "created   {}", path.display()),
+            Change::Updated(path) => write!(f, "updated   {}", path.display()),
+            Change::Unchanged(path) => write!(f, "unchanged {}", path.display()),
+        }
+    }
+}
+
+/// Creates the crate of a day and wires it into the workspace, the runner and the
+/// answers. Existing files are never overwritten, so running it again on a day only
+/// adds what is missing, like a dependency asked for this time.
+pub fn create(root: &Path, new_day: &NewDay) -> Result<Vec<Change>, String> {
+    let puzzle = new_day.puzzle;
+    check_day(puzzle.day)?;
+    let day_crate = DayCrate::new(puzzle);
+    let (name, dir) = (day_crate.name(), day_crate.dir());
+    let cargo_file = root.join(&dir).join("Cargo.toml");
+    let workspace_file = root.join("Cargo.toml");
+    let mut changes = vec![edit_toml(&workspace_file, |workspace| {
+        add_member(workspace, &dir)
+    })?];
+    for (template, target) in TEMPLATES {
+        let template_path = root.join(TEMPLATE_DIR).join(template);
+        let template = read(&template_path)?;
+        let path = root.join(&dir).join(target);
+        changes.push(write_new(&path, &new_day.fill_template(&template))?);
+    }
+    let dependencies = edit_toml(&cargo_file, |manifest| {
+        let mut changed = false;
+        for dependency in new_day.dependencies.iter() {
+            changed |= add_version_dependency(manifest, dependency.name(), dependency.version());
+        }
+        Ok(changed)
+    })?;
+    if !changes.contains(&Change::Created(cargo_file)) {
+        changes.push(dependencies);
+    }
+    let year_dir = root.join("files").join(puzzle.year.to_string());
+    let files_dir = year_dir.join(format!("day{}", puzzle.day));
+    changes.push(write_new(&files_dir.join("sample.txt"), "")?);
+    changes.push(write_new(&files_dir.join("input.txt"), "")?);
+    let runner_file = root.join("runner").join("Cargo.toml");
+    changes.push(edit_toml(&runner_file, |runner| {
+        Ok(add_path_dependency(runner, &name, &dir))
+    })?);
+    changes.push(add_runner_day(root, day_crate)?);
+    // The first day of a year also starts its answers.
+    let answers_file = year_dir.join("answers.toml");
+    let answers_created = write_new(&answers_file, "")?;
+    let answers = edit_toml(&answers_file, |answers| {
+        add_answer_tables(answers, &format!("day{}", puzzle.day))
+    })?;
+    match answers_created {
+        Change::Created(_) => changes.push(answers_created),
+        _ => changes.push(answers),
+    }
+    Ok(changes)
+}
+
+/// Takes a day out of the workspace and the runner. Its crate, puzzle files and answers
+/// are left on disk, so adding it back with `create` finds them again.
+pub fn remove(root: &Path, puzzle: Puzzle) -> Result<Vec<Change>, String> {
+    check_day(puzzle.day)?;
+    let day_crate = DayCrate::new(puzzle);
+    let (name, dir) = (day_crate.name(), day_crate.dir());
+    let workspace_file = root.join("Cargo.toml");
+    let runner_file = root.join("runner").join("Cargo.toml");
+    Ok(vec![
+        edit_toml(&workspace_file, |workspace| remove_member(workspace, &dir))?,
+        edit_toml(&runner_file, |runner| Ok(remove_dependency(runner, &name)))?,
+        remove_runner_day(root, day_crate)?,
+    ])
+}
+
+fn check_day(day: usize) -> Result<(), String> {
+    if (1..=25).contains(&day) {
+        Ok(())
+    } else {
+        Err(format!("Day {} does not exist, try 1 to 25!", day))
+    }
+}
+
+fn add_runner_day(root: &Path, day_crate: DayCrate) -> Result<Change, String> {
+    let path = root.join("runner").join("src").join("lib.rs");
+    let line = runner_day_line(day_crate);
+    edit_lines(&path, |lines| {
+        let start = match find_line(lines, "    vec![") {
+            Some(index) => index + 1,
+            None => return false,
+        };
+        let end = (start..lines.len())
+            .find(|index| lines[*index].trim() == "]")
+            .unwrap_or(lines.len());
+        let line_puzzle = |line: &String| {
+            let name = line.trim().strip_prefix("Day::of::<")?;
+            Puzzle::of_crate(name.split("::").next()?)
+        };
+        insert_by_puzzle(
+            lines,
+            start..end,
+            day_crate.puzzle,
+            line.clone(),
+            line_puzzle,
+        )
+    })
+}
+
+fn remove_runner_day(root: &Path, day_crate: DayCrate) -> Result<Change, String> {
+    let path = root.join("runner").join("src").join("lib.rs");
+    let line = runner_day_line(day_crate);
+    edit_lines(&path, |lines| {
+        let count = lines.len();
+        lines.retain(|found| *found != line);
+        lines.len() != count
+    })
+}
+
+fn runner_day_line(day_crate: DayCrate) -> String {
+    format!(
+        "        Day::of::<{}::Day{}>(),",
+        day_crate.lib_name(),
+        day_crate.puzzle.day
+    )
+}
+
+/// Inserts `line` after the last line of an earlier puzzle in `range`, unless that
+/// puzzle is already there.
+fn insert_by_puzzle(
+    lines: &mut Vec<String>,
+    range: std::ops::Range<usize>,
+    puzzle: Puzzle,
+    line: String,
+    puzzle_of: impl Fn(&String) -> Option<Puzzle>,
+) -> bool {
+    let days = range
+        .filter_map(|index| Some((index, puzzle_of(&lines[index])?)))
+        .collect::<Vec<(usize, Puzzle)>>();
+    if days.iter().any(|(_, found)| *found == puzzle) {
+        return false;
+    }
+    let index = match days.iter().rev().find(|(_, found)| *found < puzzle) {
+        Some((index, _)) => index + 1,
+        None => match days.first() {
+            Some((index, _)) => *index,
+            None => return false,
+        },
+    };
+    lines.insert(index, line);
+    true
+}
+
+fn find_line(lines: &[String], line: &str) -> Option<usize> {
+    lines.iter().position(|found| found == line)
+}
+
+/// Lets `edit` change the lines of a file, it returns whether it changed anything.
+fn edit_lines(path: &Path, edit: impl FnOnce(&mut Vec<String>) -> bool) -> Result<Change, String> {
+    let content = read(path)?;
+    let mut lines = content.lines().map(String::from).collect::<Vec<String>>();
+    if !edit(&mut lines) {
+        return Ok(Change::Unchanged(path.to_path_buf()));
+    }
+    let mut content = lines.join("\n");
+    content.push('\n');
+    write(path, &content)?;
+    Ok(Change::Updated(path.to_path_buf()))
+}
+
+fn write_new(path: &Path, content: &str) -> Result<Change, String> {
+    if path.exists() {
+        return Ok(Change::Unchanged(path.to_path_buf()));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Can not create dir {}({})", dir.display(), err))?;
+    }
+    write(path, content)?;
+    Ok(Change::Created(path.to_path_buf()))
+}
+
+pub fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Can not read {}({})", path.display(), err))
+}
+
+pub fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("Can not write {}({})", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A workspace with day1 and day3, and the real templates.
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        fs::create_dir_all(root.join("files/2022")).unwrap();
+        let templates = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(TEMPLATE_DIR);
+        fs::create_dir_all(root.join(TEMPLATE_DIR)).unwrap();
+        for (template, _) in TEMPLATES {
+            fs::copy(
+                templates.join(template),
+                root.join(TEMPLATE_DIR).join(template),
+            )
+            .unwrap();
+        }
+        let files = [
+            ("Cargo.toml", "[workspace]\n\nmembers = [\n    \"day3\",\n    \"day1\",\n]\n"),
+            (
+                "runner/Cargo.toml",
+                "[dependencies]\nbpaf = \"0.7.7\"\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\nshared = { path = \"../shared\" }\n",
+            ),
+            (
+                "runner/src/lib.rs",
+                "pub fn days() -> Vec<Day> {\n    vec![\n        Day::of::<day1::Day1>(),\n        Day::of::<day3::Day3>(),\n    ]\n}\n",
+            ),
+            ("files/2022/answers.toml", "[day1.sample]\npart1 = 1\n"),
+        ];
+        for (path, content) in files {
+            fs::write(root.join(path), content).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_create() {
+        let root = workspace("aoc-script-test-create");
+        let new_day = NewDay {
+            puzzle: Puzzle::from(2),
+            title: String::from("Test"),
+            dependencies: vec![Dependency::Nom],
+        };
+        create(&root, &new_day).unwrap();
+        let lib = fs::read_to_string(root.join("day2/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day2"));
+        assert!(!lib.contains("{{"));
+        let cargo = fs::read_to_string(root.join("day2/Cargo.toml")).unwrap();
+        assert!(cargo.contains("nom = \"7.1.1\"\nshared = { path = \"../shared\" }"));
+        assert!(cargo.ends_with("[build-dependencies]\nshared = { path = \"../shared\" }\n"));
+        assert!(root.join("day2/build.rs").exists());
+        let runner = fs::read_to_string(root.join("runner/src/lib.rs")).unwrap();
+        assert!(
+            runner.contains("Day1>(),\n        Day::of::<day2::Day2>(),\n        Day::of::<day3")
+        );
+        let runner = fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+        assert!(runner.contains("day1\" }\nday2 = { path = \"../day2\" }\nday3"));
+        let answers = fs::read_to_string(root.join("files/2022/answers.toml")).unwrap();
+        assert!(answers.ends_with("part1 = 1\n\n[day2.sample]\n\n[day2.input]\n"));
+        assert!(root.join("files/2022/day2/sample.txt").exists());
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"day1\",\n    \"day2\",\n    \"day3\","));
+    }
+
+    #[test]
+    fn test_create_other_year() {
+        let root = workspace("aoc-script-test-create-year");
+        let new_day = NewDay {
+            puzzle: Puzzle::new(2021, 7),
+            title: String::from("Test"),
+            dependencies: vec![],
+        };
+        create(&root, &new_day).unwrap();
+        let cargo = fs::read_to_string(root.join("aoc2021/day7/Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"aoc2021-day7\""));
+        assert!(cargo.contains("shared = { path = \"../../shared\" }"));
+        let lib = fs::read_to_string(root.join("aoc2021/day7/src/lib.rs")).unwrap();
+        assert!(lib.contains("year: 2021,\n        day: 7,"));
+        let runner = fs::read_to_string(root.join("runner/src/lib.rs")).unwrap();
+        assert!(runner
+            .contains("vec![\n        Day::of::<aoc2021_day7::Day7>(),\n        Day::of::<day1"));
+        let runner = fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+        assert!(runner.contains("aoc2021-day7 = { path = \"../aoc2021/day7\" }"));
+        let answers = fs::read_to_string(root.join("files/2021/answers.toml")).unwrap();
+        assert_eq!("[day7.sample]\n\n[day7.input]\n", answers);
+        assert!(root.join("files/2021/day7/input.txt").exists());
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"aoc2021/day7\",\n    \"day1\","));
+        remove(&root, Puzzle::new(2021, 7)).unwrap();
+        let runner = fs::read_to_string(root.join("runner/src/lib.rs")).unwrap();
+        assert!(!runner.contains("aoc2021"));
+    }
+
+    #[test]
+    fn test_create_again_only_adds_what_is_missing() {
+        let root = workspace("aoc-script-test-create-again");
+        let mut new_day = NewDay {
+            puzzle: Puzzle::from(4),
+            title: String::from("Test"),
+            dependencies: vec![],
+        };
+        create(&root, &new_day).unwrap();
+        fs::write(root.join("day4/src/lib.rs"), "// solved").unwrap();
+        new_day.dependencies.push(Dependency::Itertools);
+        let changes = create(&root, &new_day).unwrap();
+        let cargo_file = root.join("day4/Cargo.toml");
+        assert!(changes.contains(&Change::Updated(cargo_file.clone())));
+        assert_eq!(
+            1,
+            changes
+                .iter()
+                .filter(|change| matches!(change, Change::Updated(_)))
+                .count()
+        );
+        let cargo = fs::read_to_string(cargo_file).unwrap();
+        assert!(cargo.contains("itertools = \"0.10.5\"\nshared"));
+        let lib = fs::read_to_string(root.join("day4/src/lib.rs")).unwrap();
+        assert_eq!("// solved", lib);
+        let cargo = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert_eq!(1, cargo.matches("\"day4\"").count());
+        let puzzle = Puzzle::from(26);
+        assert!(create(&root, &NewDay { puzzle, ..new_day }).is_err());
+    }
+
+    #[test]
+    fn test_fill_template_escapes_the_title() {
+        let new_day = NewDay {
+            puzzle: Puzzle::from(5),
+            title: String::from(r#"Say "Hi" \ Bye"#),
+            dependencies: vec![],
+        };
+        assert_eq!(
+            r#"title: "Say \"Hi\" \\ Bye","#,
+            new_day.fill_template("title: {{title}},")
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let root = workspace("aoc-script-test-remove");
+        let changes = remove(&root, Puzzle::from(3)).unwrap();
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, Change::Updated(_))));
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(!workspace.contains("day3"));
+        let runner = fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+        assert!(!runner.contains("day3"));
+        let runner = fs::read_to_string(root.join("runner/src/lib.rs")).unwrap();
+        assert!(!runner.contains("day3"));
+        let changes = remove(&root, Puzzle::from(3)).unwrap();
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, Change::Unchanged(_))));
+    }
+}
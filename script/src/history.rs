@@ -0,0 +1,166 @@
+use crate::{
+    client::Verdict,
+    days::{read, write},
+};
+use shared::Part;
+use std::path::Path;
+use toml_edit::{ArrayOfTables, Document, Item, Table};
+
+/// Every judged submission of a year, as `[[submission]]` tables with `day`, `part`,
+/// `answer` and `verdict` in them.
+pub const HISTORY_FILE: &str = "submissions.toml";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Submission {
+    pub day: usize,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: String,
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    document: Document,
+}
+
+impl History {
+    /// An empty history when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let document = read(path)?
+            .parse::<Document>()
+            .map_err(|err| format!("Can not parse {}({})", path.display(), err))?;
+        Ok(Self { document })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        write(path, &self.document.to_string())
+    }
+
+    pub fn submissions(&self) -> Vec<Submission> {
+        let submissions = match self
+            .document
+            .get("submission")
+            .and_then(Item::as_array_of_tables)
+        {
+            Some(submissions) => submissions,
+            None => return Vec::new(),
+        };
+        submissions
+            .iter()
+            .filter_map(|table| {
+                Some(Submission {
+                    day: table.get("day")?.as_integer()? as usize,
+                    part: Part::try_from(table.get("part")?.as_integer()? as usize).ok()?,
+                    answer: table.get("answer")?.as_str()?.to_string(),
+                    verdict: table.get("verdict")?.as_str()?.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn record(&mut self, day: usize, part: Part, answer: &str, verdict: &Verdict) {
+        let mut table = Table::new();
+        table.insert("day", toml_edit::value(day as i64));
+        let part = match part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        table.insert("part", toml_edit::value(part));
+        table.insert("answer", toml_edit::value(answer));
+        table.insert("verdict", toml_edit::value(verdict.to_string()));
+        self.document
+            .entry("submission")
+            .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .unwrap()
+            .push(table);
+    }
+
+    /// Refuses an answer which can not be right from what the server said before, so it
+    /// does not cost a wrong submission and a wait.
+    pub fn check(&self, day: usize, part: Part, answer: &str) -> Result<(), String> {
+        let submissions = self
+            .submissions()
+            .into_iter()
+            .filter(|submission| submission.day == day && submission.part == part)
+            .collect::<Vec<Submission>>();
+        let verdict_of = |verdict: Verdict| {
+            let verdict = verdict.to_string();
+            submissions
+                .iter()
+                .filter(move |submission| submission.verdict == verdict)
+                .map(|submission| submission.answer.as_str())
+        };
+        if let Some(right) = verdict_of(Verdict::Right).next() {
+            return Err(format!("Already solved with {}!", right));
+        }
+        if submissions
+            .iter()
+            .any(|submission| submission.answer == answer)
+        {
+            return Err(format!("{} was already submitted and is wrong!", answer));
+        }
+        let number = match answer.parse::<i64>() {
+            Ok(number) => number,
+            Err(_) => return Ok(()),
+        };
+        let numbers = |verdict| verdict_of(verdict).filter_map(|answer| answer.parse::<i64>().ok());
+        if let Some(too_high) = numbers(Verdict::TooHigh).min() {
+            if number >= too_high {
+                return Err(format!("{} is too high, {} already was!", answer, too_high));
+            }
+        }
+        if let Some(too_low) = numbers(Verdict::TooLow).max() {
+            if number <= too_low {
+                return Err(format!("{} is too low, {} already was!", answer, too_low));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> History {
+        let mut history = History::default();
+        history.record(1, Part::One, "24000", &Verdict::Right);
+        history.record(1, Part::Two, "100", &Verdict::TooLow);
+        history.record(1, Part::Two, "900", &Verdict::TooHigh);
+        history.record(1, Part::Two, "500", &Verdict::Wrong);
+        history
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let path = std::env::temp_dir().join("aoc-script-test-history.toml");
+        history().save(&path).unwrap();
+        let submissions = History::load(&path).unwrap().submissions();
+        assert_eq!(4, submissions.len());
+        let expected = Submission {
+            day: 1,
+            part: Part::Two,
+            answer: String::from("900"),
+            verdict: String::from("too high"),
+        };
+        assert_eq!(expected, submissions[2]);
+    }
+
+    #[test]
+    fn test_check() {
+        let history = history();
+        assert!(history.check(1, Part::One, "24001").is_err());
+        assert!(history.check(1, Part::Two, "500").is_err());
+        assert!(history.check(1, Part::Two, "900").is_err());
+        assert!(history.check(1, Part::Two, "1000").is_err());
+        assert!(history.check(1, Part::Two, "100").is_err());
+        assert!(history.check(1, Part::Two, "42").is_err());
+        assert!(history.check(1, Part::Two, "501").is_ok());
+        assert!(history.check(1, Part::Two, "a guess").is_ok());
+        assert!(history.check(2, Part::One, "500").is_ok());
+    }
+}
@@ -1,84 +1,135 @@
+mod client;
+mod days;
+mod history;
+mod manifest;
+#[cfg(test)]
+mod mock;
+mod submit;
+
 use bpaf::Bpaf;
-use std::fs;
-use std::io::{self, Write};
-use std::process::Command;
+use client::Client;
+use days::{create, remove, Change, Dependency, NewDay};
+use shared::{Part, Puzzle, DEFAULT_YEAR};
+use std::{path::Path, process};
+use submit::submit;
 
 #[derive(Clone, Debug, Bpaf)]
 #[bpaf(options)]
-struct Options {
-    new_day: usize,
-}
-
-impl Options {
-    fn get_day(&self) -> String {
-        format!("day{}", self.new_day)
-    }
+enum Options {
+    NewDay {
+        /// Day number to create, an existing day only gets what it is missing
+        #[bpaf(long("new-day"), argument("DAY"))]
+        day: usize,
+        /// Title of the puzzle, shown by the runner
+        #[bpaf(long, argument("TITLE"))]
+        title: Option<String>,
+        /// Add nom to the dependencies
+        #[bpaf(long)]
+        nom: bool,
+        /// Add itertools to the dependencies
+        #[bpaf(long)]
+        itertools: bool,
+        /// Add test-case to the dependencies
+        #[bpaf(long("test-case"))]
+        test_case: bool,
+        /// Year of the puzzle, 2022 by default
+        #[bpaf(long, argument("YEAR"))]
+        year: Option<usize>,
+    },
+    RemoveDay {
+        /// Day number to take out of the workspace and the runner, its files are kept
+        #[bpaf(long("remove-day"), argument("DAY"))]
+        day: usize,
+        /// Year of the puzzle, 2022 by default
+        #[bpaf(long, argument("YEAR"))]
+        year: Option<usize>,
+    },
+    FetchInput {
+        /// Day number to download the puzzle input of, unless it is already there
+        #[bpaf(long("fetch-input"), argument("DAY"))]
+        day: usize,
+        /// Year of the puzzle, 2022 by default
+        #[bpaf(long, argument("YEAR"))]
+        year: Option<usize>,
+    },
+    Submit {
+        /// Day number to solve with the puzzle input and submit the answer of
+        #[bpaf(long("submit"), argument("DAY"))]
+        day: usize,
+        /// Year of the puzzle, 2022 by default
+        #[bpaf(long, argument("YEAR"))]
+        year: Option<usize>,
+        /// Part to submit, 1 or 2
+        #[bpaf(positional("PART"))]
+        part: usize,
+    },
 }
 
 fn main() {
     let options = options().run();
-    add_workspace_members(&options);
-    command_cargo_new(&options);
-    add_shared_dependency(&options);
-    create_sample_and_input(&options);
-    copy_template(&options);
-    println!("Done!");
-}
-
-fn add_workspace_members(options: &Options) {
-    let cargo_file = "Cargo.toml";
-    let insert_after = "members = [";
-    let new_line = format!("    \"{}\",", options.get_day());
-    insert_new_line_after(cargo_file, insert_after, &new_line);
-}
-
-fn command_cargo_new(options: &Options) {
-    let output = Command::new("cargo")
-        .arg("new")
-        .arg("--vcs=none")
-        .arg("--lib")
-        .arg(&options.get_day())
-        .output()
-        .expect("failed to execute process");
-    io::stdout().write_all(&output.stdout).unwrap();
-    io::stderr().write_all(&output.stderr).unwrap();
-}
-
-fn add_shared_dependency(options: &Options) {
-    let cargo_file = format!("{}/Cargo.toml", &options.get_day());
-    let insert_after = "[dependencies]";
-    let new_line = "shared = { path = \"../shared\" }";
-    insert_new_line_after(&cargo_file, insert_after, new_line);
+    match options {
+        Options::NewDay {
+            day,
+            title,
+            nom,
+            itertools,
+            test_case,
+            year,
+        } => {
+            let dependencies = [
+                (nom, Dependency::Nom),
+                (itertools, Dependency::Itertools),
+                (test_case, Dependency::TestCase),
+            ]
+            .into_iter()
+            .filter_map(|(wanted, dependency)| wanted.then_some(dependency))
+            .collect();
+            let new_day = NewDay {
+                puzzle: puzzle(year, day),
+                title: title.unwrap_or_else(|| String::from("TODO")),
+                dependencies,
+            };
+            print_changes(create(Path::new("."), &new_day))
+        }
+        Options::RemoveDay { day, year } => {
+            print_changes(remove(Path::new("."), puzzle(year, day)))
+        }
+        Options::FetchInput { day, year } => print_changes(
+            Client::from_env()
+                .and_then(|client| client.fetch_input(Path::new("."), puzzle(year, day)))
+                .map(|change| vec![change]),
+        ),
+        Options::Submit { day, year, part } => {
+            let puzzle = puzzle(year, day);
+            let part = Part::try_from(part).unwrap_or_else(|err| exit_with(&err));
+            let client = Client::from_env().unwrap_or_else(|err| exit_with(&err));
+            let (answer, verdict) =
+                submit(Path::new("."), &client, puzzle, part).unwrap_or_else(|err| exit_with(&err));
+            println!(
+                "Day {} of {} part {}: {} is {}",
+                puzzle.day, puzzle.year, part, answer, verdict
+            );
+        }
+    }
 }
 
-fn create_sample_and_input(options: &Options) {
-    let dir_name = format!("files/{}", &options.get_day());
-    fs::create_dir(&dir_name)
-        .unwrap_or_else(|err| panic!("failed to create dir {}({})", &dir_name, err));
-    let file_name = format!("{}/sample.txt", &dir_name);
-    fs::write(&file_name, "")
-        .unwrap_or_else(|err| panic!("failed to create file {}({})", &dir_name, err));
-    let file_name = format!("{}/input.txt", &dir_name);
-    fs::write(&file_name, "")
-        .unwrap_or_else(|err| panic!("failed to create file {}({})", &dir_name, err));
+fn puzzle(year: Option<usize>, day: usize) -> Puzzle {
+    Puzzle::new(year.unwrap_or(DEFAULT_YEAR), day)
 }
 
-fn copy_template(options: &Options) {
-    let template_path = "files/template/lib.rs";
-    let target_path = format!("{}/src/lib.rs", &options.get_day());
-    fs::copy(template_path, target_path).expect("Can not copy files/template/lib.rs !");
+fn print_changes(changes: Result<Vec<Change>, String>) {
+    match changes {
+        Ok(changes) => {
+            for change in changes {
+                println!("{}", change);
+            }
+            println!("Done!");
+        }
+        Err(err) => exit_with(&err),
+    }
 }
 
-fn insert_new_line_after(cargo_file: &str, insert_after: &str, new_line: &str) {
-    let cargo =
-        fs::read_to_string(cargo_file).unwrap_or_else(|_| panic!("Can not read {}!", cargo_file));
-    let mut lines = cargo.lines().collect::<Vec<&str>>();
-    let index = lines
-        .iter()
-        .position(|&r| r == insert_after)
-        .unwrap_or_else(|| panic!("Can not find {}!", insert_after));
-    lines.insert(index + 1, new_line);
-    let mut result = lines.join("\n");
-    result.push('\n');
-    fs::write(cargo_file, &result).unwrap_or_else(|_| panic!("Can not write {}!", cargo_file));
+fn exit_with(err: &str) -> ! {
+    eprintln!("{}", err);
+    process::exit(1);
 }
@@ -0,0 +1,188 @@
+use crate::days::{read, write, Change};
+use std::{cmp::Ordering, path::Path};
+use toml_edit::{table, value, Array, Document, InlineTable, Item};
+
+/// Lets `edit` change a TOML file, it returns whether it changed anything. Comments and
+/// formatting of the parts it does not touch are kept.
+pub fn edit_toml(
+    path: &Path,
+    edit: impl FnOnce(&mut Document) -> Result<bool, String>,
+) -> Result<Change, String> {
+    let mut document = read(path)?
+        .parse::<Document>()
+        .map_err(|err| format!("Can not parse {}({})", path.display(), err))?;
+    if !edit(&mut document)? {
+        return Ok(Change::Unchanged(path.to_path_buf()));
+    }
+    write(path, &document.to_string())?;
+    Ok(Change::Updated(path.to_path_buf()))
+}
+
+/// Adds a crate to `workspace.members`, which are kept sorted.
+pub fn add_member(document: &mut Document, name: &str) -> Result<bool, String> {
+    let mut members = members(document)?;
+    if members.iter().any(|member| member == name) {
+        return Ok(false);
+    }
+    members.push(name.to_string());
+    set_members(document, members)?;
+    Ok(true)
+}
+
+pub fn remove_member(document: &mut Document, name: &str) -> Result<bool, String> {
+    let members = members(document)?;
+    let count = members.len();
+    let members = members
+        .into_iter()
+        .filter(|member| member != name)
+        .collect::<Vec<String>>();
+    if members.len() == count {
+        return Ok(false);
+    }
+    set_members(document, members)?;
+    Ok(true)
+}
+
+fn members(document: &Document) -> Result<Vec<String>, String> {
+    let members = document
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(Item::as_array)
+        .ok_or("Can not find workspace.members!")?;
+    Ok(members
+        .iter()
+        .filter_map(|member| member.as_str())
+        .map(String::from)
+        .collect())
+}
+
+/// Writes the members one per line, like `cargo new` would.
+fn set_members(document: &mut Document, mut members: Vec<String>) -> Result<(), String> {
+    members.sort_by(|first, second| compare_names(first, second));
+    let array = document["workspace"]["members"]
+        .as_array_mut()
+        .ok_or("Can not find workspace.members!")?;
+    let decor = array.decor().clone();
+    let mut new_array = Array::new();
+    for member in members {
+        new_array.push(member);
+    }
+    for member in new_array.iter_mut() {
+        member.decor_mut().set_prefix("\n    ");
+    }
+    new_array.set_trailing_comma(true);
+    new_array.set_trailing("\n");
+    *new_array.decor_mut() = decor;
+    *array = new_array;
+    Ok(())
+}
+
+/// Adds `name = "version"` to `[dependencies]`, which are kept sorted.
+pub fn add_version_dependency(document: &mut Document, name: &str, version: &str) -> bool {
+    add_dependency(document, name, value(version))
+}
+
+/// Adds `name = { path = "../dir" }` to `[dependencies]`, which are kept sorted.
+pub fn add_path_dependency(document: &mut Document, name: &str, dir: &str) -> bool {
+    let mut path = InlineTable::new();
+    path.insert("path", format!("../{}", dir).into());
+    add_dependency(document, name, value(path))
+}
+
+fn add_dependency(document: &mut Document, name: &str, item: Item) -> bool {
+    let dependencies = document
+        .entry("dependencies")
+        .or_insert_with(table)
+        .as_table_mut();
+    let dependencies = match dependencies {
+        Some(dependencies) => dependencies,
+        None => return false,
+    };
+    if dependencies.contains_key(name) {
+        return false;
+    }
+    dependencies.insert(name, item);
+    dependencies.sort_values_by(|first, _, second, _| compare_names(first, second));
+    true
+}
+
+pub fn remove_dependency(document: &mut Document, name: &str) -> bool {
+    document
+        .get_mut("dependencies")
+        .and_then(Item::as_table_mut)
+        .and_then(|dependencies| dependencies.remove(name))
+        .is_some()
+}
+
+/// Adds the empty `[dayN.sample]` and `[dayN.input]` tables of `files/YEAR/answers.toml`.
+pub fn add_answer_tables(document: &mut Document, name: &str) -> Result<bool, String> {
+    let day = document.entry(name).or_insert_with(|| {
+        let mut day = table();
+        day.as_table_mut().unwrap().set_implicit(true);
+        day
+    });
+    let day = day
+        .as_table_mut()
+        .ok_or_else(|| format!("{} is not a table!", name))?;
+    let mut changed = false;
+    for input_kind in ["sample", "input"] {
+        if !day.contains_key(input_kind) {
+            day.insert(input_kind, table());
+            changed = true;
+        }
+    }
+    Ok(changed)
+}
+
+/// Orders `day2` before `day10`, and the other names alphabetically.
+fn compare_names(first: &str, second: &str) -> Ordering {
+    split_number(first).cmp(&split_number(second))
+}
+
+fn split_number(name: &str) -> (&str, Option<usize>) {
+    let prefix = name.trim_end_matches(|char: char| char.is_ascii_digit());
+    (prefix, name[prefix.len()..].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(content: &str) -> Document {
+        content.parse().unwrap()
+    }
+
+    #[test]
+    fn test_members() {
+        let mut workspace =
+            document("[workspace]\n# the days\nmembers = [\"day10\", \"shared\"]\n");
+        assert!(add_member(&mut workspace, "day9").unwrap());
+        assert!(!add_member(&mut workspace, "day9").unwrap());
+        let expected = "[workspace]\n# the days\nmembers = [\n    \"day9\",\n    \"day10\",\n    \"shared\",\n]\n";
+        assert_eq!(expected, workspace.to_string());
+        assert!(remove_member(&mut workspace, "day10").unwrap());
+        assert!(!remove_member(&mut workspace, "day10").unwrap());
+        assert!(!workspace.to_string().contains("day10"));
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let mut manifest = document("[dependencies]\nbpaf = \"0.7.7\" # cli\nday9 = { path = \"../day9\" }\nshared = { path = \"../shared\" }\n");
+        assert!(add_path_dependency(&mut manifest, "day10", "day10"));
+        assert!(add_version_dependency(&mut manifest, "nom", "7.1.1"));
+        assert!(!add_version_dependency(&mut manifest, "nom", "7.1.1"));
+        let expected = "[dependencies]\nbpaf = \"0.7.7\" # cli\nday9 = { path = \"../day9\" }\nday10 = { path = \"../day10\" }\nnom = \"7.1.1\"\nshared = { path = \"../shared\" }\n";
+        assert_eq!(expected, manifest.to_string());
+        assert!(remove_dependency(&mut manifest, "day9"));
+        assert!(!remove_dependency(&mut manifest, "day9"));
+    }
+
+    #[test]
+    fn test_answer_tables() {
+        let mut answers = document("[day1.sample]\npart1 = 1\n");
+        assert!(add_answer_tables(&mut answers, "day2").unwrap());
+        assert!(!add_answer_tables(&mut answers, "day2").unwrap());
+        let expected = "[day1.sample]\npart1 = 1\n\n[day2.sample]\n\n[day2.input]\n";
+        assert_eq!(expected, answers.to_string());
+    }
+}
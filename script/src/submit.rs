@@ -0,0 +1,125 @@
+use crate::{
+    client::{Client, Verdict},
+    history::{History, HISTORY_FILE},
+};
+use shared::{Part, Puzzle};
+use std::{
+    env,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+/// Solves a part with `aoc run` and submits the answer, unless the history already tells
+/// it is wrong. Judged answers are added to the history.
+pub fn submit(
+    root: &Path,
+    client: &Client,
+    puzzle: Puzzle,
+    part: Part,
+) -> Result<(String, Verdict), String> {
+    let answer = run_part(root, puzzle, part)?;
+    let verdict = submit_answer(root, client, puzzle, part, &answer)?;
+    Ok((answer, verdict))
+}
+
+/// Submits `answer` unless the history already tells it is wrong, a judged one is added
+/// to the history.
+pub fn submit_answer(
+    root: &Path,
+    client: &Client,
+    puzzle: Puzzle,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let path = root
+        .join("files")
+        .join(puzzle.year.to_string())
+        .join(HISTORY_FILE);
+    let mut history = History::load(&path)?;
+    history.check(puzzle.day, part, answer)?;
+    let verdict = client.submit_answer(puzzle, part, answer)?;
+    if verdict.is_judgement() {
+        history.record(puzzle.day, part, answer, &verdict);
+        history.save(&path)?;
+    }
+    Ok(verdict)
+}
+
+/// Runs the runner in release mode, this crate does not build every day just to solve one.
+fn run_part(root: &Path, puzzle: Puzzle, part: Part) -> Result<String, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let output = Command::new(cargo)
+        .current_dir(root)
+        .args(["run", "--release", "--quiet", "-p", "runner", "--", "run"])
+        .args(["--year", &puzzle.year.to_string()])
+        .args(["--part", &part.to_string(), &puzzle.day.to_string()])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("Can not run the runner({})", err))?;
+    if !output.status.success() {
+        return Err(format!("The runner failed on the {}!", puzzle));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    parse_answer(&stdout, puzzle, part)
+}
+
+/// Reads the answer of `part` out of what `aoc run` prints, like `Part 2: 70`. An answer
+/// over more lines is a picture to read, not something to submit.
+fn parse_answer(output: &str, puzzle: Puzzle, part: Part) -> Result<String, String> {
+    let prefix = format!("Part {}:", part);
+    let no_answer = || format!("The {} part {} has no answer to submit!", puzzle, part);
+    let answer = output
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .ok_or_else(no_answer)?;
+    match answer.trim() {
+        "" => Err(format!(
+            "The {} part {} answer spans several lines, it can not be submitted as it is!",
+            puzzle, part
+        )),
+        "no answer" => Err(no_answer()),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::mock_server;
+    use std::fs;
+
+    #[test]
+    fn test_submit_answer() {
+        let root = env::temp_dir().join("aoc-script-test-submit");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("files/2022")).unwrap();
+        let page =
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+        let (base_url, requests) = mock_server("200 OK", page);
+        let client = Client::new(&base_url, "secret");
+        let puzzle = Puzzle::from(1);
+        let verdict = submit_answer(&root, &client, puzzle, Part::One, "24000").unwrap();
+        assert_eq!(Verdict::Right, verdict);
+        let request = requests.recv().unwrap();
+        assert!(request.ends_with("level=1&answer=24000"));
+        // Already right, so it is refused before reaching the server which is gone.
+        let err = submit_answer(&root, &client, puzzle, Part::One, "24000").unwrap_err();
+        assert!(err.starts_with("Already solved"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        let puzzle = Puzzle::from(1);
+        let output = "Day 1 of 2022: Calorie Counting\nPart 2: 45000\n";
+        assert_eq!(
+            Ok(String::from("45000")),
+            parse_answer(output, puzzle, Part::Two)
+        );
+        let err = parse_answer(output, puzzle, Part::One).unwrap_err();
+        assert!(err.ends_with("has no answer to submit!"), "{}", err);
+        let err = parse_answer("Part 1: no answer\n", puzzle, Part::One).unwrap_err();
+        assert!(err.ends_with("has no answer to submit!"), "{}", err);
+        let err = parse_answer("Part 1:\n#..#\n", puzzle, Part::One).unwrap_err();
+        assert!(err.contains("spans several lines"), "{}", err);
+    }
+}
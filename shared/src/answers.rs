@@ -0,0 +1,111 @@
+use crate::{
+    file_path, parse::in_input_file, read_kind, try_read_file, year_dir, InputError, InputKind,
+    Part, Puzzle, Solution,
+};
+use toml::Value;
+
+/// Known answers of a year, keyed like `[day7.input]` with `part1` and `part2` in it.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+pub fn try_read_answer(
+    puzzle: impl Into<Puzzle>,
+    input_kind: InputKind,
+    part: Part,
+) -> Result<Option<String>, InputError> {
+    let puzzle = puzzle.into();
+    let path = year_dir(puzzle.year)?.join(ANSWERS_FILE);
+    let answers = try_read_file(&path)?
+        .parse::<Value>()
+        .map_err(|err| InputError::BadAnswers {
+            path: path.clone(),
+            message: err.to_string(),
+        })?;
+    let answer = answers
+        .get(format!("day{}", puzzle.day))
+        .and_then(|day| day.get(input_kind.name()))
+        .and_then(|answers| answers.get(format!("part{}", part)));
+    answer
+        .map(|answer| {
+            answer_string(answer).map_err(|message| InputError::BadAnswers {
+                path: path.clone(),
+                message,
+            })
+        })
+        .transpose()
+}
+
+/// Answers are written as integers when they are numbers, and as strings otherwise.
+pub(crate) fn answer_string(answer: &Value) -> Result<String, String> {
+    match answer {
+        Value::String(answer) => Ok(answer.clone()),
+        Value::Integer(answer) => Ok(answer.to_string()),
+        other => Err(format!("{} is not a string or an integer", other)),
+    }
+}
+
+/// Solves the file of `input_kind` and compares it with the one in `files/YEAR/answers.toml`.
+/// A missing file fails it, `answer_tests!` ignores the input tests of a missing input.
+pub fn check_answer<S: Solution>(input_kind: InputKind, part: Part) {
+    let puzzle = S::METADATA.puzzle();
+    let content = read_kind(puzzle, input_kind);
+    let expected = try_read_answer(puzzle, input_kind, part)
+        .unwrap_or_else(|err| panic!("{}", err))
+        .unwrap_or_else(|| {
+            panic!(
+                "No answer of {} part{} for {} in {}!",
+                puzzle,
+                part,
+                input_kind.file_name(),
+                ANSWERS_FILE
+            )
+        });
+    let params = S::params(input_kind).unwrap_or_else(|err| panic!("{}", err));
+    let path = file_path(puzzle, input_kind).unwrap_or_else(|err| panic!("{}", err));
+    let answer = in_input_file(path, || S::solve(part, &content, &params));
+    assert_eq!(Some(expected), answer);
+}
+
+/// Generates `process_partN_with_sample` and `process_partN_with_input` tests which
+/// check a `Solution` against `files/YEAR/answers.toml`. Pass `Sample` or `Input` as the
+/// second argument to only generate half of them, or `Samples` for a `process_with_samples`
+/// test which checks every sample in `files/YEAR/dayN/samples`. The input tests are ignored
+/// when the build script of the day finds no input, see `ignore_missing_input`.
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty) => {
+        $crate::answer_tests!($solution, Sample);
+        $crate::answer_tests!($solution, Input);
+    };
+    ($solution:ty, Sample) => {
+        #[test]
+        #[cfg_attr(unanswered = "sample1", ignore = "the sample answer is missing")]
+        fn process_part1_with_sample() {
+            $crate::check_answer::<$solution>($crate::InputKind::Sample, $crate::Part::One);
+        }
+
+        #[test]
+        #[cfg_attr(unanswered = "sample2", ignore = "the sample answer is missing")]
+        fn process_part2_with_sample() {
+            $crate::check_answer::<$solution>($crate::InputKind::Sample, $crate::Part::Two);
+        }
+    };
+    ($solution:ty, Samples) => {
+        #[test]
+        fn process_with_samples() {
+            $crate::check_samples::<$solution>();
+        }
+    };
+    ($solution:ty, Input) => {
+        #[test]
+        #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
+        fn process_part1_with_input() {
+            $crate::check_answer::<$solution>($crate::InputKind::Input, $crate::Part::One);
+        }
+
+        #[test]
+        #[cfg_attr(missing_input, ignore = "the puzzle input is missing")]
+        fn process_part2_with_input() {
+            $crate::check_answer::<$solution>($crate::InputKind::Input, $crate::Part::Two);
+        }
+    };
+}
@@ -0,0 +1,214 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A sequence of states which repeats itself: the state after `start + length` steps is
+/// the same as the one after `start` steps, and so on forever.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step in the first lap which has the same state as `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// The value of a metric after `step` steps, for metrics which grow by the same amount
+    /// every lap like the height of a tower. `metrics[i]` is the value after `i` steps and
+    /// must go at least up to `start + length`.
+    pub fn extrapolate(&self, metrics: &[usize], step: usize) -> usize {
+        if step < metrics.len() {
+            return metrics[step];
+        }
+        let end = self.start + self.length;
+        assert!(
+            end < metrics.len(),
+            "Need the metrics up to step {} to extrapolate!",
+            end
+        );
+        let laps = (step - self.start) / self.length;
+        let per_lap = metrics[end] - metrics[self.start];
+        metrics[self.equivalent_step(step)] + laps * per_lap
+    }
+
+    /// Whether a metric grows by the same amount over every lap of `laps` more laps, which
+    /// tells a cycle found with a fingerprint that leaves out part of the state from a
+    /// false one. `metrics` must go up to `start + (laps + 1) * length`.
+    pub fn repeats(&self, metrics: &[usize], laps: usize) -> bool {
+        let end = self.start + (laps + 1) * self.length;
+        assert!(
+            end < metrics.len(),
+            "Need the metrics up to step {} to check the cycle!",
+            end
+        );
+        let per_lap = metrics[self.start + self.length] - metrics[self.start];
+        (self.start..end - self.length)
+            .all(|step| metrics[step + self.length] - metrics[step] == per_lap)
+    }
+}
+
+/// Floyd's tortoise and hare. `next` must lead into a cycle at some point, as any sequence
+/// of a finite amount of states does, or this never returns.
+pub fn floyd<T: Clone + Eq>(initial: T, mut next: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        hare = next(&hare);
+    }
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, same as `floyd` but calls `next` fewer times.
+pub fn brent<T: Clone + Eq>(initial: T, mut next: impl FnMut(&T) -> T) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds a cycle by remembering a fingerprint of every state, for states too big to clone
+/// or compare, or simulations which can only go forward. The fingerprint must tell apart
+/// every two states which do not go on the same way.
+#[derive(Debug)]
+pub struct Detector<K> {
+    seen: HashMap<K, usize>,
+    step: usize,
+}
+
+impl<K: Eq + Hash> Detector<K> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+            step: 0,
+        }
+    }
+
+    /// Records the fingerprint of the state after the next step, starting from step 0 for
+    /// the initial state. Returns the cycle once a fingerprint comes back.
+    pub fn record(&mut self, key: K) -> Option<Cycle> {
+        let step = self.step;
+        self.step += 1;
+        self.seen.insert(key, step).map(|start| Cycle {
+            start,
+            length: step - start,
+        })
+    }
+}
+
+impl<K: Eq + Hash> Default for Detector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The first cycle in the fingerprints of a sequence of states.
+pub fn find_by_key<K: Eq + Hash>(keys: impl IntoIterator<Item = K>) -> Option<Cycle> {
+    let mut detector = Detector::new();
+    keys.into_iter().find_map(|key| detector.record(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, ..., 9, then 3, 4, ..., 9 again, so the cycle starts at 3 and has 7 steps.
+    fn next(state: &usize) -> usize {
+        if *state == 9 {
+            3
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn test_floyd_and_brent() {
+        let expected = Cycle {
+            start: 3,
+            length: 7,
+        };
+        assert_eq!(expected, floyd(0, next));
+        assert_eq!(expected, brent(0, next));
+        assert_eq!(expected.length, brent(5, next).length);
+        assert_eq!(0, brent(5, next).start);
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        let states = std::iter::successors(Some(0), |state| Some(next(state)));
+        let cycle = find_by_key(states).unwrap();
+        assert_eq!((3, 7), (cycle.start, cycle.length));
+        assert_eq!(None, find_by_key(0..100));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let cycle = Cycle {
+            start: 3,
+            length: 7,
+        };
+        assert_eq!(5, cycle.equivalent_step(19));
+        // Grows by 1 until the cycle, then by 2 each step.
+        let metrics = (0..=10)
+            .map(|step| if step < 3 { step } else { 2 * step - 3 })
+            .collect::<Vec<usize>>();
+        assert_eq!(metrics[7], cycle.extrapolate(&metrics, 7));
+        assert_eq!(2 * 1_000_000 - 3, cycle.extrapolate(&metrics, 1_000_000));
+    }
+
+    #[test]
+    fn test_repeats() {
+        let cycle = Cycle {
+            start: 3,
+            length: 7,
+        };
+        let mut metrics = (0..=24)
+            .map(|step| if step < 3 { step } else { 2 * step - 3 })
+            .collect::<Vec<usize>>();
+        assert!(cycle.repeats(&metrics, 2));
+        // The first lap grew like the ones after it, but the second one did not.
+        metrics[15] += 1;
+        assert!(!cycle.repeats(&metrics, 2));
+        assert!(cycle.repeats(&metrics[..=10], 0));
+    }
+}
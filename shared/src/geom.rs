@@ -0,0 +1,552 @@
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::Hash,
+    iter,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A number a point is made of, the integers up to 64 bits.
+pub trait Coordinate:
+    Copy + Debug + Ord + Hash + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `|self - other|`, which an unsigned one can not compute with a `-`.
+    fn distance(self, other: Self) -> Self;
+
+    /// `self + delta`, `None` when it does not fit, like stepping left of zero.
+    fn offset(self, delta: isize) -> Option<Self>;
+
+    /// Fits every one of them, so sizes can be counted without overflowing.
+    fn as_i128(self) -> i128;
+}
+
+macro_rules! coordinate {
+    ($($number:ty),*) => {
+        $(
+            impl Coordinate for $number {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn distance(self, other: Self) -> Self {
+                    if self > other {
+                        self - other
+                    } else {
+                        other - self
+                    }
+                }
+
+                fn offset(self, delta: isize) -> Option<Self> {
+                    Self::try_from(self as i128 + delta as i128).ok()
+                }
+
+                fn as_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+fn signum<T: Coordinate + Neg<Output = T>>(number: T) -> T {
+    match number.cmp(&T::ZERO) {
+        Ordering::Less => -T::ONE,
+        Ordering::Equal => T::ZERO,
+        Ordering::Greater => T::ONE,
+    }
+}
+
+/// From `min` to `max`, both included.
+fn span<T: Coordinate>(min: T, max: T) -> impl Iterator<Item = T> + Clone {
+    iter::successors(Some(min).filter(|min| *min <= max), move |number| {
+        if *number < max {
+            number.offset(1)
+        } else {
+            None
+        }
+    })
+}
+
+/// A step on a map, `North` is up with `y` growing downwards like the puzzle maps.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+    /// Clockwise from `North`, so an eighth of a turn is one step in it.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Turns clockwise by `eighths` of a full turn, counterclockwise when negative.
+    pub fn rotate(self, eighths: isize) -> Direction {
+        Direction::ALL[(self as isize + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn turn_around(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !Direction::ORTHOGONAL.contains(self)
+    }
+}
+
+/// The arrows, `U`/`R`/`D`/`L` and the compass letters of the puzzles.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(char: char) -> Result<Self, Self::Error> {
+        match char {
+            '^' | 'U' | 'N' => Ok(Direction::North),
+            '>' | 'R' | 'E' => Ok(Direction::East),
+            'v' | 'D' | 'S' => Ok(Direction::South),
+            '<' | 'L' | 'W' => Ok(Direction::West),
+            _ => Err(format!("{} is not a direction!", char)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// The point one step away in `direction`, `None` when it does not fit in `T`.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        Some(Self::new(self.x.offset(dx)?, self.y.offset(dy)?))
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(direction))
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Point2<T> {
+    /// A quarter turn around the origin, clockwise with `y` growing downwards.
+    pub fn rotate_clockwise(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate_counterclockwise(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Every coordinate turned into -1, 0 or 1, the step towards where it points.
+    pub fn signum(self) -> Self {
+        Self::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl From<Direction> for Point2<isize> {
+    fn from(direction: Direction) -> Self {
+        let (x, y) = direction.offset();
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+
+    /// `None` when it does not fit in `T`.
+    pub fn offset(self, dx: isize, dy: isize, dz: isize) -> Option<Self> {
+        Some(Self::new(
+            self.x.offset(dx)?,
+            self.y.offset(dy)?,
+            self.z.offset(dz)?,
+        ))
+    }
+
+    /// The points sharing a face with it.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+
+    /// The points sharing a face, an edge or a corner with it.
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        let deltas = -1..=1;
+        deltas
+            .clone()
+            .flat_map(move |dz| {
+                deltas
+                    .clone()
+                    .flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz)))
+            })
+            .filter(|delta| *delta != (0, 0, 0))
+            .filter_map(move |(dx, dy, dz)| self.offset(dx, dy, dz))
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident, $($field:ident),+) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        /// Scales every coordinate.
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+
+point_ops!(Point2, x, y);
+point_ops!(Point3, x, y, z);
+
+/// The smallest box around some points, both corners are in it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Bounds2<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coordinate> Bounds2<T> {
+    pub fn new(point: Point2<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// `None` without any points.
+    pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    pub fn extend(&mut self, point: Point2<T>) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn is_edge(&self, point: Point2<T>) -> bool {
+        self.contains(point)
+            && (point.x == self.min.x
+                || point.x == self.max.x
+                || point.y == self.min.y
+                || point.y == self.max.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    /// Every point in it, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2<T>> {
+        let xs = span(self.min.x, self.max.x);
+        span(self.min.y, self.max.y).flat_map(move |y| xs.clone().map(move |x| Point2::new(x, y)))
+    }
+}
+
+/// The smallest box around some points in space, both corners are in it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Bounds3<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Coordinate> Bounds3<T> {
+    pub fn new(point: Point3<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// `None` without any points.
+    pub fn from_points(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    pub fn extend(&mut self, point: Point3<T>) {
+        self.min = Point3::new(
+            self.min.x.min(point.x),
+            self.min.y.min(point.y),
+            self.min.z.min(point.z),
+        );
+        self.max = Point3::new(
+            self.max.x.max(point.x),
+            self.max.y.max(point.y),
+            self.max.z.max(point.z),
+        );
+    }
+
+    pub fn contains(&self, point: Point3<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn is_edge(&self, point: Point3<T>) -> bool {
+        self.contains(point)
+            && (point.x == self.min.x
+                || point.x == self.max.x
+                || point.y == self.min.y
+                || point.y == self.max.y
+                || point.z == self.min.z
+                || point.z == self.max.z)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn depth(&self) -> T {
+        self.max.z - self.min.z + T::ONE
+    }
+
+    /// Every point in it, a layer of `z` after the other and row by row in a layer.
+    pub fn points(&self) -> impl Iterator<Item = Point3<T>> {
+        let xs = span(self.min.x, self.max.x);
+        let ys = span(self.min.y, self.max.y);
+        span(self.min.z, self.max.z).flat_map(move |z| {
+            let xs = xs.clone();
+            ys.clone()
+                .flat_map(move |y| xs.clone().map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::SouthWest, Direction::NorthEast.turn_around());
+        assert_eq!(Direction::NorthWest, Direction::North.rotate(-1));
+        assert_eq!(Direction::North, Direction::West.rotate(10));
+        assert!(Direction::SouthEast.is_diagonal());
+        assert_eq!(Ok(Direction::South), Direction::try_from('v'));
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn test_point2() {
+        let point = Point2::new(3isize, -2);
+        assert_eq!(Point2::new(4, -4), point + Point2::new(1, -2));
+        assert_eq!(Point2::new(6, -4), point * 2);
+        assert_eq!(5, point.manhattan(Point2::ORIGIN));
+        assert_eq!(3, point.chebyshev(Point2::ORIGIN));
+        assert_eq!(Point2::new(2, 3), point.rotate_clockwise());
+        assert_eq!(point, point.rotate_clockwise().rotate_counterclockwise());
+        assert_eq!(Point2::new(1, -1), point.signum());
+        assert_eq!(
+            point + Point2::from(Direction::East),
+            point.step(Direction::East).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let corner = Point2::new(0usize, 0);
+        let neighbours = corner.neighbours4().collect::<Vec<_>>();
+        assert_eq!(vec![Point2::new(1, 0), Point2::new(0, 1)], neighbours);
+        assert_eq!(8, Point2::new(1usize, 1).neighbours8().count());
+        assert_eq!(6, Point3::new(1u8, 1, 1).neighbours6().count());
+        assert_eq!(3, Point3::new(0u8, 0, 0).neighbours6().count());
+        let around = Point3::new(1i32, 1, 1).neighbours26().collect::<Vec<_>>();
+        assert_eq!(26, around.len());
+        assert!(around
+            .iter()
+            .all(|point| point.chebyshev(Point3::new(1, 1, 1)) == 1));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let points = [Point2::new(2usize, 5), Point2::new(4, 3), Point2::new(3, 4)];
+        let bounds = Bounds2::from_points(points).unwrap();
+        assert_eq!(
+            (Point2::new(2, 3), Point2::new(4, 5)),
+            (bounds.min, bounds.max)
+        );
+        assert_eq!((3, 3), (bounds.width(), bounds.height()));
+        assert!(bounds.contains(Point2::new(3, 3)) && !bounds.contains(Point2::new(1, 3)));
+        assert!(bounds.is_edge(Point2::new(4, 4)) && !bounds.is_edge(Point2::new(3, 4)));
+        assert_eq!(9, bounds.points().count());
+        assert_eq!(Some(Point2::new(3, 3)), bounds.points().nth(1));
+        let bounds = Bounds3::from_points([Point3::new(0, 0, 0), Point3::new(1, 2, 3)]).unwrap();
+        assert_eq!(24, bounds.points().count());
+        assert_eq!(Some(Point3::new(0, 0, 1)), bounds.points().nth(6));
+        assert!(Bounds2::<usize>::from_points([]).is_none());
+    }
+}
@@ -0,0 +1,307 @@
+mod sparse;
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+pub use crate::geom::Direction;
+pub use sparse::SparseGrid;
+
+/// A position in a dense `Grid`, `(x, y)` with `y` growing downwards like the puzzle maps.
+pub type Position = (usize, usize);
+
+/// A rectangular grid stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, item: T) -> Self {
+        Self::new(width, height, vec![item; width * height])
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "A {}x{} grid needs {} cells!",
+            width,
+            height,
+            width * height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a character map, `None` if the lines are not all the same length.
+    pub fn parse(content: &str, mut to_cell: impl FnMut(char) -> T) -> Option<Self> {
+        let width = content.lines().next()?.chars().count();
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in content.lines() {
+            if line.chars().count() != width {
+                return None;
+            }
+            cells.extend(line.chars().map(&mut to_cell));
+            height += 1;
+        }
+        Some(Self::new(width, height, cells))
+    }
+
+    /// Parses a character map with ragged lines, the short ones are padded with `fill`.
+    pub fn parse_padded(content: &str, fill: char, mut to_cell: impl FnMut(char) -> T) -> Self {
+        let width = content
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in content.lines() {
+            let chars = line.chars().chain(std::iter::repeat(fill)).take(width);
+            cells.extend(chars.map(&mut to_cell));
+            height += 1;
+        }
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        let index = self.index_of(position)?;
+        self.cells.get(index)
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let index = self.index_of(position)?;
+        self.cells.get_mut(index)
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        if self.contains((x, y)) {
+            Some(x + y * self.width)
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.enumerate()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, to_cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(to_cell).collect(),
+        )
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[x + y * self.width])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The position one step away in `direction`, `None` when it leaves the grid.
+    pub fn step(&self, (x, y): Position, direction: Direction) -> Option<Position> {
+        let (dx, dy) = direction.offset();
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if self.contains((x, y)) {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions from `start` to the edge in `direction`, `start` excluded.
+    pub fn ray(
+        &self,
+        start: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.step(start, direction), move |position| {
+            self.step(*position, direction)
+        })
+    }
+
+    pub fn transpose(self) -> Self {
+        let (width, height) = (self.width, self.height);
+        self.rearrange(height, width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(self) -> Self {
+        let (width, height) = (self.width, self.height);
+        self.rearrange(height, width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(self) -> Self {
+        let (width, height) = (self.width, self.height);
+        self.rearrange(height, width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Builds a `width` x `height` grid, `source` maps each new position to the old one.
+    fn rearrange(
+        self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> Position,
+    ) -> Self {
+        let old_width = self.width;
+        let mut cells = self.cells.into_iter().map(Some).collect::<Vec<Option<T>>>();
+        let mut new_cells = Vec::with_capacity(cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (old_x, old_y) = source(x, y);
+                new_cells.push(cells[old_x + old_y * old_width].take().unwrap());
+            }
+        }
+        Self::new(width, height, new_cells)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid!", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid!", position))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef", |char| char).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get((2, 1)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert!(Grid::parse("ab\nc", |char| char).is_none());
+        let padded = Grid::parse_padded("ab\nc", ' ', |char| char);
+        assert_eq!("ab\nc ", padded.to_string());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+        assert_eq!(
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f']],
+            grid.rows().collect::<Vec<_>>()
+        );
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<String>>();
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+    }
+
+    #[test]
+    fn test_neighbours_and_ray() {
+        let grid = sample();
+        let neighbours = grid.neighbours4((0, 0)).collect::<Vec<Position>>();
+        assert_eq!(vec![(1, 0), (0, 1)], neighbours);
+        assert_eq!(5, grid.neighbours8((1, 0)).count());
+        let ray = grid.ray((0, 0), Direction::East).collect::<Vec<Position>>();
+        assert_eq!(vec![(1, 0), (2, 0)], ray);
+    }
+
+    #[test]
+    fn test_rotate_and_transpose() {
+        assert_eq!("da\neb\nfc", sample().rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", sample().rotate_counterclockwise().to_string());
+        assert_eq!("ad\nbe\ncf", sample().transpose().to_string());
+        let full_turn = (0..4).fold(sample(), |grid, _| grid.rotate_clockwise());
+        assert_eq!(sample(), full_turn);
+    }
+}
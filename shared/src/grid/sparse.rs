@@ -0,0 +1,136 @@
+use super::Direction;
+use std::{collections::HashMap, fmt::Display};
+
+/// A grid which only stores the occupied cells, for maps that grow or have no fixed size.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses a character map, keeping the cells `to_cell` returns `Some` for.
+    pub fn parse(content: &str, mut to_cell: impl FnMut(char) -> Option<T>) -> Self {
+        let mut cells = HashMap::new();
+        for (y, line) in content.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                if let Some(cell) = to_cell(char) {
+                    cells.insert((x as isize, y as isize), cell);
+                }
+            }
+        }
+        Self { cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: (isize, isize)) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn get(&self, position: (isize, isize)) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: (isize, isize)) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn insert(&mut self, position: (isize, isize), cell: T) -> Option<T> {
+        self.cells.insert(position, cell)
+    }
+
+    pub fn remove(&mut self, position: (isize, isize)) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &T)> {
+        self.cells.iter().map(|(position, cell)| (*position, cell))
+    }
+
+    /// The smallest and largest corner of the occupied cells.
+    pub fn bounds(&self) -> Option<((isize, isize), (isize, isize))> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), (x, y)| {
+            (
+                (min.0.min(*x), min.1.min(*y)),
+                (max.0.max(*x), max.1.max(*y)),
+            )
+        }))
+    }
+
+    pub fn neighbours4(&self, position: (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+        Self::neighbours(position, &Direction::ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, position: (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+        Self::neighbours(position, &Direction::ALL)
+    }
+
+    fn neighbours(
+        (x, y): (isize, isize),
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (isize, isize)> {
+        directions.iter().map(move |direction| {
+            let (dx, dy) = direction.offset();
+            (x + dx, y + dy)
+        })
+    }
+}
+
+impl<T> FromIterator<((isize, isize), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((isize, isize), T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Renders the bounding box of the occupied cells, with `.` for the empty ones.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ((min_x, min_y), (max_x, max_y)) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        for y in min_y..=max_y {
+            if y > min_y {
+                writeln!(f)?;
+            }
+            for x in min_x..=max_x {
+                match self.get((x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render() {
+        let mut grid = SparseGrid::parse("..#\n#..", |char| (char == '#').then_some('#'));
+        assert_eq!(2, grid.len());
+        assert_eq!(Some(((0, 0), (2, 1))), grid.bounds());
+        grid.insert((-1, 0), '#');
+        assert_eq!("#..#\n.#..", grid.to_string());
+        assert_eq!(8, grid.neighbours8((0, 0)).count());
+    }
+}
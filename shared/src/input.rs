@@ -0,0 +1,298 @@
+use crate::{try_read_answer, InputKind, Part, Puzzle, ANSWERS_FILE};
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Overrides the workspace root, which is otherwise the parent of this crate.
+pub const ROOT_ENV: &str = "AOC_ROOT";
+
+/// Makes the input tests fail instead of being ignored when a puzzle input is missing.
+pub const REQUIRE_INPUT_ENV: &str = "AOC_REQUIRE_INPUT";
+
+/// Stands for stdin wherever a path is read, like `--input -` of the runner.
+pub const STDIN_PATH: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingFile { path: PathBuf, source: io::Error },
+    Io { path: PathBuf, source: io::Error },
+    EmptyFile { path: PathBuf },
+    BadRoot { path: PathBuf },
+    BadAnswers { path: PathBuf, message: String },
+    BadParams { path: PathBuf, message: String },
+    BadSamples { path: PathBuf, message: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingFile { path, source } | InputError::Io { path, source } => {
+                write!(f, "Fail to read file {}({})", path.display(), source)
+            }
+            InputError::EmptyFile { path } => write!(f, "{} is empty!", path.display()),
+            InputError::BadRoot { path } => write!(
+                f,
+                "{} is not the workspace root, set {} to the directory with files/ in it!",
+                path.display(),
+                ROOT_ENV
+            ),
+            InputError::BadAnswers { path, message }
+            | InputError::BadParams { path, message }
+            | InputError::BadSamples { path, message } => {
+                write!(f, "Fail to parse {}({})", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::MissingFile { source, .. } | InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub fn workspace_root() -> Result<PathBuf, InputError> {
+    let root = match env::var_os(ROOT_ENV) {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default(),
+    };
+    if !root.join("files").is_dir() {
+        return Err(InputError::BadRoot { path: root });
+    }
+    Ok(root)
+}
+
+/// Where the files of a year are, like `files/2022`.
+pub fn year_dir(year: usize) -> Result<PathBuf, InputError> {
+    Ok(workspace_root()?.join("files").join(year.to_string()))
+}
+
+/// Where the files of a day are, like `files/2022/day5`.
+pub fn day_dir(puzzle: impl Into<Puzzle>) -> Result<PathBuf, InputError> {
+    let puzzle = puzzle.into();
+    Ok(year_dir(puzzle.year)?.join(format!("day{}", puzzle.day)))
+}
+
+pub fn file_path(puzzle: impl Into<Puzzle>, input_kind: InputKind) -> Result<PathBuf, InputError> {
+    Ok(day_dir(puzzle)?.join(input_kind.file_name()))
+}
+
+pub fn try_read_kind(
+    puzzle: impl Into<Puzzle>,
+    input_kind: InputKind,
+) -> Result<String, InputError> {
+    try_read_file(file_path(puzzle, input_kind)?)
+}
+
+pub fn try_read_sample(puzzle: impl Into<Puzzle>) -> Result<String, InputError> {
+    try_read_kind(puzzle, InputKind::Sample)
+}
+
+pub fn try_read_input(puzzle: impl Into<Puzzle>) -> Result<String, InputError> {
+    try_read_kind(puzzle, InputKind::Input)
+}
+
+/// The build script of a day crate calls this, it sets `cfg(missing_input)` when the puzzle
+/// input is missing or empty, and `answer_tests!` ignores the input tests then. Inputs are
+/// personal, so a fresh clone does not have them. A part without a sample answer yet, as in a
+/// day fresh from the template, sets `cfg(unanswered = "sample1")` or `"sample2"` to ignore
+/// its sample test the same way.
+pub fn ignore_missing_input() {
+    println!("cargo:rustc-check-cfg=cfg(missing_input)");
+    println!("cargo:rustc-check-cfg=cfg(unanswered, values(\"sample1\", \"sample2\"))");
+    println!("cargo:rerun-if-env-changed={}", ROOT_ENV);
+    println!("cargo:rerun-if-env-changed={}", REQUIRE_INPUT_ENV);
+    let name = env::var("CARGO_PKG_NAME").unwrap_or_default();
+    let puzzle = match Puzzle::of_crate(&name) {
+        Some(puzzle) => puzzle,
+        None => return,
+    };
+    ignore_unanswered_samples(puzzle);
+    let path = match file_path(puzzle, InputKind::Input) {
+        Ok(path) => path,
+        Err(_) => return,
+    };
+    // A whole directory is watched for files coming and going, a missing path would rerun
+    // the script on every build.
+    if let Some(day_dir) = path.parent().filter(|day_dir| day_dir.is_dir()) {
+        println!("cargo:rerun-if-changed={}", day_dir.display());
+    }
+    let is_missing = fs::metadata(&path).map_or(true, |metadata| metadata.len() == 0);
+    if is_missing && env::var_os(REQUIRE_INPUT_ENV).is_none() {
+        println!("cargo:rustc-cfg=missing_input");
+    }
+}
+
+/// A broken answers file is left to the tests to report, only a missing answer is skipped.
+fn ignore_unanswered_samples(puzzle: Puzzle) {
+    if let Ok(path) = year_dir(puzzle.year).map(|dir| dir.join(ANSWERS_FILE)) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    for part in Part::all() {
+        if let Ok(None) = try_read_answer(puzzle, InputKind::Sample, part) {
+            println!("cargo:rustc-cfg=unanswered=\"sample{}\"", part);
+        }
+    }
+}
+
+/// Reads a whole file, or stdin for `STDIN_PATH`. Like every input it goes through
+/// `LineReader`, so one with `\r\n` line endings reads like one saved by the browser.
+pub fn try_read_file(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    non_empty(LineReader::open(path)?.read_rest()?, path)
+}
+
+/// Reads all of `reader`, `path` only tells where it comes from in errors.
+pub fn try_read_from(reader: impl Read, path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    non_empty(
+        LineReader::new(BufReader::new(reader), path).read_rest()?,
+        path,
+    )
+}
+
+fn non_empty(content: String, path: &Path) -> Result<String, InputError> {
+    if content.is_empty() {
+        return Err(InputError::EmptyFile {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(content)
+}
+
+/// `MissingFile` when there is nothing at `path`, `Io` for everything else like a directory
+/// or a file which is not UTF-8.
+pub(crate) fn read_error(path: &Path, source: io::Error) -> InputError {
+    let path = path.to_path_buf();
+    match source.kind() {
+        io::ErrorKind::NotFound => InputError::MissingFile { path, source },
+        _ => InputError::Io { path, source },
+    }
+}
+
+/// Reads an input one line at a time, taking `\n` and `\r\n` both as a line ending.
+struct LineReader<R> {
+    reader: R,
+    path: PathBuf,
+    line: String,
+}
+
+impl LineReader<Box<dyn BufRead>> {
+    /// Opens a file, or stdin for `STDIN_PATH`.
+    fn open(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let reader: Box<dyn BufRead> = if path == Path::new(STDIN_PATH) {
+            Box::new(io::stdin().lock())
+        } else {
+            let file = File::open(path).map_err(|source| read_error(path, source))?;
+            Box::new(BufReader::new(file))
+        };
+        Ok(Self::new(reader, path))
+    }
+}
+
+impl<R: BufRead> LineReader<R> {
+    fn new(reader: R, path: impl AsRef<Path>) -> Self {
+        Self {
+            reader,
+            path: path.as_ref().to_path_buf(),
+            line: String::new(),
+        }
+    }
+
+    /// The next line without its line ending, `None` after the last one.
+    fn next_line(&mut self) -> Result<Option<&str>, InputError> {
+        self.line.clear();
+        let read = self
+            .reader
+            .read_line(&mut self.line)
+            .map_err(|source| read_error(&self.path, source))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        let line = self.line.strip_suffix('\n').unwrap_or(&self.line);
+        Ok(Some(line.strip_suffix('\r').unwrap_or(line)))
+    }
+
+    /// The lines left joined into one string, each ends with `\n` whatever it ended with.
+    fn read_rest(&mut self) -> Result<String, InputError> {
+        let mut content = String::new();
+        while let Some(line) = self.next_line()? {
+            content.push_str(line);
+            content.push('\n');
+        }
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn try_read_file_missing() {
+        let path = env::temp_dir().join("shared_try_read_file_missing.txt");
+        let result = try_read_file(&path);
+        assert!(matches!(result, Err(InputError::MissingFile { .. })));
+    }
+
+    #[test]
+    fn try_read_file_not_a_file() {
+        let result = try_read_file(env::temp_dir());
+        assert!(matches!(result, Err(InputError::Io { .. })));
+        let result = try_read_from(&[0xff, 0xfe][..], STDIN_PATH);
+        assert!(matches!(result, Err(InputError::Io { .. })));
+    }
+
+    #[test]
+    fn try_read_file_empty() {
+        let path = env::temp_dir().join("shared_try_read_file_empty.txt");
+        fs::write(&path, "").unwrap();
+        let result = try_read_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(InputError::EmptyFile { .. })));
+    }
+
+    #[test]
+    fn try_read_from_reader() {
+        let content = try_read_from("30373\n25512\n".as_bytes(), STDIN_PATH).unwrap();
+        assert_eq!("30373\n25512\n", content);
+        let content = try_read_from("30373\r\n25512".as_bytes(), STDIN_PATH).unwrap();
+        assert_eq!("30373\n25512\n", content);
+        let result = try_read_from(io::empty(), STDIN_PATH);
+        assert!(matches!(result, Err(InputError::EmptyFile { .. })));
+    }
+
+    #[test]
+    fn line_reader_next_line() {
+        let mut lines = LineReader::new("1000\r\n2000\n\n3000".as_bytes(), STDIN_PATH);
+        let mut read = Vec::new();
+        while let Some(line) = lines.next_line().unwrap() {
+            read.push(line.to_string());
+        }
+        assert_eq!(vec!["1000", "2000", "", "3000"], read);
+        let mut lines = LineReader::new("1000\r\n2000\r\n\r\n3000".as_bytes(), STDIN_PATH);
+        assert_eq!(Some("1000"), lines.next_line().unwrap());
+        assert_eq!("2000\n\n3000\n", lines.read_rest().unwrap());
+        assert!(LineReader::open(file_path(6, InputKind::Sample).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn try_read_sample_from_workspace() {
+        let content = try_read_sample(6).unwrap();
+        assert!(content.starts_with("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+        let path = file_path(Puzzle::new(2021, 5), InputKind::Input).unwrap();
+        assert!(path.ends_with("files/2021/day5/input.txt"));
+    }
+}
@@ -0,0 +1,280 @@
+use crate::geom::Coordinate;
+use std::ops::RangeInclusive;
+
+/// A set of integers kept as the sorted ranges it is made of. Overlapping and touching
+/// ranges are merged, so `1..=2` and `3..=4` are stored as `1..=4`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RangeSet<T> {
+    /// Sorted, with a gap of at least one number between two of them.
+    ranges: Vec<(T, T)>,
+}
+
+/// The one after `number`, `number` itself at the end of `T`.
+fn next<T: Coordinate>(number: T) -> T {
+    number.offset(1).unwrap_or(number)
+}
+
+impl<T: Coordinate> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many numbers are in it, as a `u128` since a set of all the numbers of `T` has
+    /// one more than `T` can count.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end.as_i128() - start.as_i128()) as u128 + 1)
+            .sum()
+    }
+
+    /// The merged ranges, from the lowest.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn first(&self) -> Option<T> {
+        self.ranges.first().map(|&(start, _)| start)
+    }
+
+    pub fn last(&self) -> Option<T> {
+        self.ranges.last().map(|&(_, end)| end)
+    }
+
+    pub fn contains(&self, number: T) -> bool {
+        let index = self.ranges.partition_point(|&(_, end)| end < number);
+        self.ranges
+            .get(index)
+            .is_some_and(|&(start, _)| start <= number)
+    }
+
+    /// Whether every number of `range` is in it, an empty `range` always is.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self
+            .ranges
+            .partition_point(|&(_, end)| end < *range.start());
+        self.ranges
+            .get(index)
+            .is_some_and(|&(start, end)| start <= *range.start() && *range.end() <= end)
+    }
+
+    /// Whether every number of `other` is in it.
+    pub fn is_superset(&self, other: &RangeSet<T>) -> bool {
+        other.iter().all(|range| self.contains_range(&range))
+    }
+
+    /// Whether any number of `range` is in it.
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let index = self
+            .ranges
+            .partition_point(|&(_, end)| end < *range.start());
+        self.ranges
+            .get(index)
+            .is_some_and(|&(start, _)| start <= *range.end())
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The ranges before `first` end before `start` with a gap, the ones from `last`
+        // start after `end` with a gap, the ones between are merged into it.
+        let first = self
+            .ranges
+            .partition_point(|&(_, other_end)| next(other_end) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(other_start, _)| other_start <= next(end));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|&(_, other_end)| other_end < start);
+        let last = self
+            .ranges
+            .partition_point(|&(other_start, _)| other_start <= end);
+        let mut kept = Vec::new();
+        if first < last {
+            let (first_start, _) = self.ranges[first];
+            let (_, last_end) = self.ranges[last - 1];
+            if first_start < start {
+                kept.push((first_start, start.offset(-1).unwrap()));
+            }
+            if end < last_end {
+                kept.push((end.offset(1).unwrap(), last_end));
+            }
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        other.iter().for_each(|range| union.insert(range));
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (0, 0);
+        while left < self.ranges.len() && right < other.ranges.len() {
+            let (left_start, left_end) = self.ranges[left];
+            let (right_start, right_end) = other.ranges[right];
+            let (start, end) = (left_start.max(right_start), left_end.min(right_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if left_end < right_end {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut difference = self.clone();
+        other.iter().for_each(|range| difference.remove(range));
+        difference
+    }
+
+    /// The numbers of `bounds` which are not in it.
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> RangeSet<T> {
+        RangeSet::from(bounds).difference(self)
+    }
+}
+
+impl<T: Coordinate> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coordinate> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        ranges.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use std::collections::BTreeSet;
+
+    /// Small ranges in `-20..=20`, some of them empty, from a fixed seed.
+    struct Ranges(Rng);
+
+    impl Iterator for Ranges {
+        type Item = RangeInclusive<i32>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let start = self.0.isize_in(-20..=20) as i32;
+            let length = self.0.isize_in(-7..=7) as i32;
+            Some(start..=start + length)
+        }
+    }
+
+    fn brute_force(set: &RangeSet<i32>) -> BTreeSet<i32> {
+        set.iter().flatten().collect()
+    }
+
+    fn is_merged(set: &RangeSet<i32>) -> bool {
+        set.ranges.windows(2).all(|pair| pair[0].1 + 1 < pair[1].0)
+            && set.ranges.iter().all(|(start, end)| start <= end)
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = RangeSet::from(1..=2);
+        set.insert(3..=4);
+        set.insert(8..=9);
+        assert_eq!(vec![1..=4, 8..=9], set.iter().collect::<Vec<_>>());
+        set.remove(2..=3);
+        let (start, end) = (6, 5);
+        set.insert(start..=end);
+        assert_eq!(vec![1..=1, 4..=4, 8..=9], set.iter().collect::<Vec<_>>());
+        assert_eq!(4, set.len());
+        assert_eq!((Some(1), Some(9)), (set.first(), set.last()));
+        let gaps = set.gaps(0..=10);
+        assert_eq!(
+            vec![0..=0, 2..=3, 5..=7, 10..=10],
+            gaps.iter().collect::<Vec<_>>()
+        );
+        let mut full = RangeSet::from(usize::MIN..=usize::MAX);
+        full.remove(0..=0);
+        full.insert(0..=0);
+        assert_eq!(vec![0..=usize::MAX], full.iter().collect::<Vec<_>>());
+        assert_eq!(1 << 64, RangeSet::from(u64::MIN..=u64::MAX).len());
+        assert_eq!(1 << 32, RangeSet::from(i32::MIN..=i32::MAX).len());
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let mut ranges = Ranges(Rng::new(0x2022_1204_1215));
+        for _ in 0..500 {
+            let mut set = RangeSet::new();
+            let mut expected = BTreeSet::new();
+            for (index, range) in ranges.by_ref().take(6).enumerate() {
+                if index % 3 == 2 {
+                    range.clone().for_each(|number| {
+                        expected.remove(&number);
+                    });
+                    set.remove(range);
+                } else {
+                    expected.extend(range.clone());
+                    set.insert(range);
+                }
+                assert!(is_merged(&set), "{:?}", set);
+                assert_eq!(expected, brute_force(&set));
+            }
+            assert_eq!(expected.len(), set.len() as usize);
+            let other = ranges.by_ref().take(3).collect::<RangeSet<i32>>();
+            let other_expected = brute_force(&other);
+            let checks = [
+                (set.union(&other), &expected | &other_expected),
+                (set.intersection(&other), &expected & &other_expected),
+                (set.difference(&other), &expected - &other_expected),
+                (set.gaps(-10..=10), &(-10..=10).collect() - &expected),
+            ];
+            for (result, expected) in checks {
+                assert!(is_merged(&result), "{:?}", result);
+                assert_eq!(expected, brute_force(&result));
+            }
+            let range = ranges.next().unwrap();
+            let numbers = range.clone().collect::<BTreeSet<i32>>();
+            assert_eq!(numbers.is_subset(&expected), set.contains_range(&range));
+            assert_eq!(!numbers.is_disjoint(&expected), set.overlaps(&range));
+            assert_eq!(
+                expected.contains(range.start()),
+                set.contains(*range.start())
+            );
+            assert_eq!(other_expected.is_subset(&expected), set.is_superset(&other));
+        }
+    }
+}
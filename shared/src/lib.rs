@@ -1,56 +1,38 @@
-use std::{
-    env,
-    fs::{self, OpenOptions},
-    io::Write,
-};
-mod math;
+mod answers;
+pub mod cycle;
+pub mod geom;
+pub mod grid;
+mod input;
+pub mod intervals;
+pub mod logging;
+pub mod math;
+mod params;
+pub mod parse;
+pub mod progress;
+pub mod random;
+mod samples;
+pub mod search;
+mod solution;
 
+pub use answers::{check_answer, try_read_answer, ANSWERS_FILE};
+pub use input::{
+    day_dir, file_path, ignore_missing_input, try_read_file, try_read_from, try_read_input,
+    try_read_kind, try_read_sample, workspace_root, year_dir, InputError, REQUIRE_INPUT_ENV,
+    ROOT_ENV, STDIN_PATH,
+};
 pub use math::lcm;
+pub use params::{try_read_params, PARAMS_FILE};
+pub use samples::{check_samples, try_read_samples, Sample, SAMPLES_DIR, SAMPLES_FILE};
+pub use solution::{InputKind, Metadata, Part, Puzzle, Solution, DEFAULT_YEAR};
 
-pub fn read_file() -> String {
-    let args: Vec<String> = env::args().collect();
-    let file_path = args.get(1).expect("No file path found in args!");
-    let file_path = file_path.as_str();
-    read_file_internal(file_path)
-}
-
-pub fn read_sample(day_num: usize) -> String {
-    let sample_path = file_path(day_num, "sample.txt");
-    let content = read_file_internal(&sample_path);
-    if content.is_empty() {
-        panic!("sample.txt is empty!")
-    }
-    content
-}
-
-pub fn read_input(day_num: usize) -> String {
-    let input_path = file_path(day_num, "input.txt");
-    let content = read_file_internal(&input_path);
-    if content.is_empty() {
-        panic!("input.txt is empty!")
-    }
-    content
-}
-
-fn file_path(day_num: usize, file_name: &str) -> String {
-    let root = env::current_dir().unwrap();
-    let root = root.parent().unwrap();
-    let binding = root
-        .join("files")
-        .join(format!("day{}", day_num))
-        .join(file_name);
-    String::from(binding.to_str().unwrap())
+pub fn read_sample(puzzle: impl Into<Puzzle>) -> String {
+    read_kind(puzzle, InputKind::Sample)
 }
 
-fn read_file_internal(file_path: &str) -> String {
-    fs::read_to_string(file_path.to_owned())
-        .expect(format!("Fail to read file {}", file_path).as_str())
+pub fn read_input(puzzle: impl Into<Puzzle>) -> String {
+    read_kind(puzzle, InputKind::Input)
 }
 
-pub fn log(text: &str) {
-    let root = env::current_dir().unwrap();
-    let root = root.parent().unwrap();
-    let binding = root.join("log").join("output.log");
-    let mut file = OpenOptions::new().append(true).open(binding).unwrap();
-    write!(file, "{}\n", text).unwrap();
+pub fn read_kind(puzzle: impl Into<Puzzle>, input_kind: InputKind) -> String {
+    try_read_kind(puzzle, input_kind).unwrap_or_else(|err| panic!("{}", err))
 }
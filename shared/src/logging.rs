@@ -0,0 +1,245 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+};
+
+/// Turns logging on, it is off without it. A comma separated list of a default level and
+/// `target=level` pairs, like `info,day19=trace,day16::simulator=off`. The target of a
+/// line is the module it is logged in, so the name of a day covers all of its modules.
+pub const LOG_ENV: &str = "AOC_LOG";
+/// Appends the lines to this file instead of writing them to stderr, it is created with
+/// the first line.
+pub const LOG_FILE_ENV: &str = "AOC_LOG_FILE";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// `None` stands for `off`.
+    fn parse(name: &str) -> Result<Option<Level>, String> {
+        match name.trim().to_lowercase().as_str() {
+            "off" => Ok(None),
+            "error" => Ok(Some(Level::Error)),
+            "warn" => Ok(Some(Level::Warn)),
+            "info" => Ok(Some(Level::Info)),
+            "debug" => Ok(Some(Level::Debug)),
+            "trace" => Ok(Some(Level::Trace)),
+            _ => Err(format!(
+                "{} is not a level, try off, error, warn, info, debug or trace!",
+                name
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        // `pad`, so `{:<5}` lines the levels up.
+        f.pad(name)
+    }
+}
+
+/// What `AOC_LOG` asks for.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+    /// The most verbose level of all, anything above it is never logged.
+    max: Option<Level>,
+}
+
+impl Filter {
+    fn parse(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::default();
+        for item in spec.split(',').filter(|item| !item.trim().is_empty()) {
+            match item.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.trim().to_string(), Level::parse(level)?)),
+                None => filter.default = Level::parse(item)?,
+            }
+        }
+        // The longest target is the most specific one, so it is looked at first.
+        filter
+            .targets
+            .sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+        filter.max = filter
+            .targets
+            .iter()
+            .map(|(_, level)| *level)
+            .chain([filter.default])
+            .max()
+            .flatten();
+        Ok(filter)
+    }
+
+    fn enabled(&self, level: Level, target: &str) -> bool {
+        if self.max.is_none_or(|max| level > max) {
+            return false;
+        }
+        let level_of_target = self
+            .targets
+            .iter()
+            .find(|(prefix, _)| is_within(target, prefix))
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default);
+        level_of_target.is_some_and(|max| level <= max)
+    }
+}
+
+/// `day19::parser` is within `day19`, `day1` is not within `day19` though.
+fn is_within(target: &str, prefix: &str) -> bool {
+    target == prefix
+        || target
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with("::"))
+}
+
+struct Logger {
+    filter: Filter,
+    file_path: Option<PathBuf>,
+    /// Opened on the first line, so an unused `AOC_LOG_FILE` is never created.
+    file: Mutex<Option<File>>,
+}
+
+fn logger() -> &'static Logger {
+    static LOGGER: OnceLock<Logger> = OnceLock::new();
+    LOGGER.get_or_init(|| {
+        let filter = match env::var(LOG_ENV) {
+            Ok(spec) => Filter::parse(&spec).unwrap_or_else(|err| {
+                eprintln!("{} is ignored: {}", LOG_ENV, err);
+                Filter::default()
+            }),
+            Err(_) => Filter::default(),
+        };
+        Logger {
+            filter,
+            file_path: env::var_os(LOG_FILE_ENV).map(PathBuf::from),
+            file: Mutex::new(None),
+        }
+    })
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    logger().filter.enabled(level, target)
+}
+
+/// Writes a line, the macros check `enabled` before calling it.
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    let logger = logger();
+    let line = format!("[{:<5} {}] {}", level, target, args);
+    let path = match &logger.file_path {
+        Some(path) => path,
+        None => {
+            eprintln!("{}", line);
+            return;
+        }
+    };
+    let mut file = logger.file.lock().unwrap_or_else(|err| err.into_inner());
+    if file.is_none() {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            let _ = fs::create_dir_all(dir);
+        }
+        match OpenOptions::new().create(true).append(true).open(path) {
+            Ok(opened) => *file = Some(opened),
+            Err(err) => {
+                eprintln!("Can not open {}({}), {}", path.display(), err, line);
+                return;
+            }
+        }
+    }
+    if let Some(file) = file.as_mut() {
+        let _ = writeln!(file, "{}", line);
+    }
+}
+
+/// Logs at a `Level` for the module it is called in. The arguments are only formatted
+/// when the level is on, so a line nobody asked for costs a check.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if $crate::logging::enabled(level, module_path!()) {
+            $crate::logging::write(level, module_path!(), format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::logging::Level::Error, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::logging::Level::Warn, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::logging::Level::Info, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::logging::Level::Debug, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::logging::Level::Trace, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter::parse("info, day19=trace,day19::parser=off").unwrap();
+        assert!(filter.enabled(Level::Info, "day1"));
+        assert!(!filter.enabled(Level::Debug, "day1"));
+        assert!(filter.enabled(Level::Trace, "day19::simulator"));
+        assert!(filter.enabled(Level::Trace, "day19"));
+        assert!(!filter.enabled(Level::Error, "day19::parser"));
+        assert!(!filter.enabled(Level::Trace, "day190"));
+        assert_eq!(Some(Level::Trace), filter.max);
+    }
+
+    #[test]
+    fn test_filter_off() {
+        let filter = Filter::default();
+        assert!(!filter.enabled(Level::Error, "day1"));
+        let filter = Filter::parse("day16=debug").unwrap();
+        assert!(filter.enabled(Level::Debug, "day16"));
+        assert!(!filter.enabled(Level::Error, "day17"));
+        assert!(Filter::parse("day16=loud").is_err());
+    }
+}
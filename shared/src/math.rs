@@ -1,21 +1,334 @@
-pub fn lcm(first: usize, second: usize) -> usize {
-    first * second / gcd(first, second)
+use crate::geom::Coordinate;
+use std::ops::{Div, Neg, Rem};
+
+/// The primitive integers, what the functions of this module work on. They are the
+/// coordinates of `geom`, which can also be divided.
+pub trait Integer: Coordinate + Div<Output = Self> + Rem<Output = Self> {
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// `None` for the lowest signed one divided by -1, whose remainder is 0 though.
+    fn checked_rem(self, other: Self) -> Option<Self>;
+
+    /// The remainder which is never negative, like `rem_euclid` of the primitives.
+    fn rem_euclid(self, other: Self) -> Self;
+
+    /// `None` for the lowest signed one, its opposite does not fit.
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($abs:ident, $($number:ty),*) => {
+        $(
+            impl Integer for $number {
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$number>::checked_mul(self, other)
+                }
+
+                fn checked_rem(self, other: Self) -> Option<Self> {
+                    <$number>::checked_rem(self, other)
+                }
+
+                fn rem_euclid(self, other: Self) -> Self {
+                    <$number>::rem_euclid(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    integer!(@$abs self)
+                }
+            }
+        )*
+    };
+    (@signed $number:ident) => {
+        $number.checked_abs()
+    };
+    (@unsigned $number:ident) => {
+        Some($number)
+    };
+}
+
+integer!(signed, i8, i16, i32, i64, isize);
+integer!(unsigned, u8, u16, u32, u64, usize);
+
+fn two<T: Integer>() -> T {
+    T::ONE + T::ONE
+}
+
+/// The greatest common divisor, never negative. `gcd(n, 0)` is `|n|` and `gcd(0, 0)` is 0.
+/// `None` when it does not fit in `T`, which only happens for `T::MIN` with 0 or itself.
+pub fn gcd<T: Integer>(first: T, second: T) -> Option<T> {
+    // A remainder has the sign of the dividend, so negative ones go through as they are
+    // and only the result has to be made positive.
+    let (mut max, mut min) = (first, second);
+    while min != T::ZERO {
+        (max, min) = (min, max.checked_rem(min).unwrap_or(T::ZERO));
+    }
+    max.checked_abs()
+}
+
+/// The least common multiple, never negative. `None` when it does not fit in `T`, and 0
+/// when any of them is.
+pub fn lcm<T: Integer>(first: T, second: T) -> Option<T> {
+    if first == T::ZERO || second == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Dividing first keeps it from overflowing when the result fits.
+    (first / gcd(first, second)?)
+        .checked_mul(second)?
+        .checked_abs()
+}
+
+/// The least common multiple of all of them, 1 for none.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |multiple, number| lcm(multiple, number))
+}
+
+/// `(gcd, x, y)` with `first * x + second * y == gcd`, the `gcd` is not negative.
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(first: T, second: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (first, second);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `number * x ≡ 1`, `None` when they share a divisor.
+pub fn mod_inverse<T: Integer + Neg<Output = T>>(number: T, modulus: T) -> Option<T> {
+    assert!(
+        modulus > T::ZERO,
+        "The modulus {:?} is not positive!",
+        modulus
+    );
+    let (gcd, x, _) = extended_gcd(number.rem_euclid(modulus), modulus);
+    if gcd == T::ONE {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/// `(first + second) % modulus` for both in `0..modulus`, without overflowing.
+fn add_mod<T: Integer>(first: T, second: T, modulus: T) -> T {
+    if first >= modulus - second {
+        first - (modulus - second)
+    } else {
+        first + second
+    }
 }
 
-pub fn gcd(first: usize, second: usize) -> usize {
-    let mut max = first;
-    let mut min = second;
-    if min > max {
-        let val = max;
-        max = min;
-        min = val;
+/// `(first * second) % modulus`, which is still right when the product overflows.
+pub fn mul_mod<T: Integer>(first: T, second: T, modulus: T) -> T {
+    assert!(
+        modulus > T::ZERO,
+        "The modulus {:?} is not positive!",
+        modulus
+    );
+    let (mut first, mut second) = (first.rem_euclid(modulus), second.rem_euclid(modulus));
+    if let Some(product) = first.checked_mul(second) {
+        return product % modulus;
     }
-    loop {
-        let res = max % min;
-        if res == 0 {
-            return min;
+    let mut result = T::ZERO;
+    while second > T::ZERO {
+        if second % two() == T::ONE {
+            result = add_mod(result, first, modulus);
+        }
+        first = add_mod(first, first, modulus);
+        second = second / two();
+    }
+    result
+}
+
+/// `base` to the power of `exponent`, in `0..modulus`.
+pub fn mod_pow<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+    assert!(
+        modulus > T::ZERO,
+        "The modulus {:?} is not positive!",
+        modulus
+    );
+    assert!(
+        exponent >= T::ZERO,
+        "The exponent {:?} is negative!",
+        exponent
+    );
+    let (mut base, mut exponent) = (base.rem_euclid(modulus), exponent);
+    let mut result = T::ONE % modulus;
+    while exponent > T::ZERO {
+        if exponent % two() == T::ONE {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent = exponent / two();
+    }
+    result
+}
+
+/// Solves `x ≡ remainder (mod modulus)` for all the pairs together, the moduli do not
+/// have to be coprime. `Some((x, lcm))` with the smallest `x` that is not negative, every
+/// `x + k * lcm` is a solution too. `None` when they contradict each other or the `lcm`
+/// does not fit in `T`.
+pub fn crt<T: Integer + Neg<Output = T>>(
+    congruences: impl IntoIterator<Item = (T, T)>,
+) -> Option<(T, T)> {
+    congruences.into_iter().try_fold(
+        (T::ZERO, T::ONE),
+        |(x, modulus), (remainder, other_modulus)| {
+            assert!(
+                other_modulus > T::ZERO,
+                "The modulus {:?} is not positive!",
+                other_modulus
+            );
+            let remainder = remainder.rem_euclid(other_modulus);
+            let gcd = gcd(modulus, other_modulus)?;
+            let difference = remainder - x.rem_euclid(other_modulus);
+            if difference % gcd != T::ZERO {
+                return None;
+            }
+            let lcm = (modulus / gcd).checked_mul(other_modulus)?;
+            // x + modulus * t ≡ remainder, so t ≡ difference / gcd * inverse (mod step).
+            let step = other_modulus / gcd;
+            let inverse = mod_inverse(modulus / gcd, step)?;
+            let t = mul_mod(difference / gcd, inverse, step);
+            // `x < modulus` and `modulus * t <= lcm - modulus`, so it stays below `lcm`.
+            Some((x + modulus * t, lcm))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(Some(6), gcd(12usize, 18));
+        assert_eq!(Some(5), gcd(0, -5));
+        assert_eq!(Some(0), gcd(0u8, 0));
+        assert_eq!(Some(2), gcd(i64::MIN, -6));
+        assert_eq!(Some(1), gcd(i64::MIN, -1));
+        assert_eq!(None, gcd(i64::MIN, 0));
+        assert_eq!(None, gcd(i8::MIN, i8::MIN));
+        assert_eq!(None, lcm(i64::MIN, 1));
+        assert_eq!(None, lcm(-1, i64::MIN));
+        assert_eq!(Some(0), lcm(i64::MIN, 0));
+        assert_eq!(Some(i64::MAX), lcm(i64::MAX, -1));
+        assert_eq!(Some(36), lcm(12usize, 18));
+        assert_eq!(Some(0), lcm(0, 7));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+        assert_eq!(Some(u64::MAX), lcm(u64::MAX, u64::MAX));
+        assert_eq!(Some(9699690), lcm_all([2u64, 3, 5, 7, 11, 13, 17, 19]));
+        assert_eq!(Some(1), lcm_all(Vec::<i32>::new()));
+    }
+
+    #[test]
+    fn test_mod_functions() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(None, mod_inverse(4, 6));
+        assert_eq!(445, mod_pow(4u32, 13, 497));
+        assert_eq!(0, mod_pow(7, 0, 1));
+        let big = u64::MAX - 58;
+        assert_eq!(big - 1, mul_mod(big - 1, 1, big));
+        assert_eq!(1, mul_mod(big - 1, big - 1, big));
+        assert_eq!(Some((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((10, 12)), crt([(4, 6), (2, 4)]));
+        assert_eq!(None, crt([(1, 4), (2, 6)]));
+    }
+
+    /// Small ones, where the results are easy to check, and the ends of `i64`.
+    fn edgy_i64() -> impl Strategy<Value = i64> {
+        prop_oneof![
+            4 => -10_000i64..10_000,
+            1 => Just(i64::MIN),
+            1 => Just(i64::MIN + 1),
+            1 => Just(i64::MAX),
+            1 => any::<i64>(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_both(first in edgy_i64(), second in edgy_i64()) {
+            let expected = gcd(first.unsigned_abs(), second.unsigned_abs()).unwrap();
+            prop_assert_eq!(i64::try_from(expected).ok(), gcd(first, second));
+            if let Some(divisor) = gcd(first, second).filter(|divisor| *divisor > 0) {
+                prop_assert_eq!(0, first % divisor);
+                prop_assert_eq!(0, second % divisor);
+                prop_assert_eq!(Some(1), gcd(first / divisor, second / divisor));
+            }
+        }
+
+        #[test]
+        fn lcm_is_checked(first in any::<u32>(), second in any::<u32>()) {
+            let expected = first as u64 * second as u64 / gcd(first, second).unwrap().max(1) as u64;
+            prop_assert_eq!(u32::try_from(expected).ok(), lcm(first, second));
+        }
+
+        #[test]
+        fn lcm_is_checked_at_the_ends(first in edgy_i64(), second in edgy_i64()) {
+            let (first_abs, second_abs) = (first.unsigned_abs() as u128, second.unsigned_abs() as u128);
+            let divisor = gcd(first.unsigned_abs(), second.unsigned_abs()).unwrap().max(1) as u128;
+            let expected = first_abs * second_abs / divisor;
+            prop_assert_eq!(i64::try_from(expected).ok(), lcm(first, second));
+        }
+
+        #[test]
+        fn extended_gcd_is_bezout(first in -1_000_000i64..1_000_000, second in -1_000_000i64..1_000_000) {
+            let (divisor, x, y) = extended_gcd(first, second);
+            prop_assert_eq!(gcd(first, second), Some(divisor));
+            prop_assert_eq!(divisor, first * x + second * y);
+        }
+
+        #[test]
+        fn mod_inverse_inverts(number in -1_000i64..1_000, modulus in 1i64..1_000) {
+            match mod_inverse(number, modulus) {
+                Some(inverse) => {
+                    prop_assert!((0..modulus).contains(&inverse));
+                    prop_assert_eq!(1 % modulus, (number * inverse).rem_euclid(modulus));
+                }
+                None => prop_assert!(gcd(number, modulus) != Some(1)),
+            }
+        }
+
+        #[test]
+        fn mod_pow_matches_u128(base in any::<u64>(), exponent in 0u64..200, modulus in 1u64..) {
+            let modulus_128 = modulus as u128;
+            let expected = (0..exponent).fold(1 % modulus_128, |power, _| {
+                power * (base as u128 % modulus_128) % modulus_128
+            });
+            prop_assert_eq!(expected as u64, mod_pow(base, exponent, modulus));
+        }
+
+        #[test]
+        fn crt_solves_all(congruences in prop::collection::vec((any::<i64>(), 1i64..30), 0..4)) {
+            match crt(congruences.clone()) {
+                Some((x, modulus)) => {
+                    prop_assert!((0..modulus).contains(&x));
+                    for (remainder, other_modulus) in congruences {
+                        prop_assert_eq!(remainder.rem_euclid(other_modulus), x % other_modulus);
+                    }
+                }
+                None => {
+                    // Only contradicting ones fail with moduli this small, no `x` below
+                    // the product of the moduli solves them.
+                    let bound = congruences.iter().map(|(_, modulus)| modulus).product::<i64>();
+                    let solved = (0..bound).any(|x| {
+                        congruences.iter().all(|(remainder, modulus)| {
+                            remainder.rem_euclid(*modulus) == x % modulus
+                        })
+                    });
+                    prop_assert!(!solved);
+                }
+            }
         }
-        max = min;
-        min = res;
     }
 }
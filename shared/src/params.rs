@@ -0,0 +1,89 @@
+use crate::{day_dir, try_read_file, InputError, InputKind, Puzzle};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+use toml::Value;
+
+/// Parameters of a day in `files/YEAR/dayN`, a `[sample]` and an `[input]` table with the
+/// fields of its `Solution::Params` in them.
+pub const PARAMS_FILE: &str = "params.toml";
+
+/// Reads the parameters to solve the file of `input_kind` with. Without a `[sample]`
+/// table the sample takes the `[input]` one, and without either it is `P::default()`.
+pub fn try_read_params<P: Default + DeserializeOwned>(
+    puzzle: impl Into<Puzzle>,
+    input_kind: InputKind,
+) -> Result<P, InputError> {
+    try_read_params_in(&day_dir(puzzle)?, input_kind)
+}
+
+fn try_read_params_in<P: Default + DeserializeOwned>(
+    day_dir: &Path,
+    input_kind: InputKind,
+) -> Result<P, InputError> {
+    let path = day_dir.join(PARAMS_FILE);
+    if !path.is_file() {
+        return Ok(P::default());
+    }
+    let bad_params = |message: String| InputError::BadParams {
+        path: path.clone(),
+        message,
+    };
+    let params = try_read_file(&path)?
+        .parse::<Value>()
+        .map_err(|err| bad_params(err.to_string()))?;
+    let table = match input_kind {
+        InputKind::Sample => params.get("sample").or_else(|| params.get("input")),
+        InputKind::Input => params.get("input"),
+    };
+    match table {
+        Some(table) => table
+            .clone()
+            .try_into()
+            .map_err(|err| bad_params(err.to_string())),
+        None => Ok(P::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::{env, fs, path::PathBuf};
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    struct Params {
+        face_size: usize,
+    }
+
+    #[test]
+    fn try_read_params_from_workspace() {
+        let sample = try_read_params::<Params>(22, InputKind::Sample).unwrap();
+        assert_eq!(Params { face_size: 4 }, sample);
+        let input = try_read_params::<Params>(22, InputKind::Input).unwrap();
+        assert_eq!(Params { face_size: 50 }, input);
+        let missing = try_read_params::<Params>(1, InputKind::Sample).unwrap();
+        assert_eq!(Params::default(), missing);
+    }
+
+    /// Removes the dir when dropped, so a failing assert does not leave it behind.
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn try_read_params_bad_file() {
+        let dir = TempDir(env::temp_dir().join("shared-test-params-bad-file"));
+        fs::create_dir_all(&dir.0).unwrap();
+        let path = dir.0.join(PARAMS_FILE);
+        fs::write(&path, "[sample\nface_size = 4\n").unwrap();
+        let not_toml = try_read_params_in::<Params>(&dir.0, InputKind::Sample);
+        assert!(matches!(not_toml, Err(InputError::BadParams { .. })));
+        fs::write(&path, "[sample]\nface_size = \"four\"\n").unwrap();
+        let wrong_type = try_read_params_in::<Params>(&dir.0, InputKind::Sample);
+        assert!(matches!(wrong_type, Err(InputError::BadParams { .. })));
+    }
+}
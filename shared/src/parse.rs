@@ -0,0 +1,353 @@
+use crate::grid::Grid;
+use nom::{
+    character::complete::{char, digit1, line_ending, not_line_ending, one_of, space0},
+    combinator::{opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::pair,
+    IResult,
+};
+use std::{
+    cell::RefCell,
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+thread_local! {
+    /// Where the content being solved on this thread was read from, see `in_input_file`.
+    static INPUT_FILE: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Where a parser stopped in an input, with the line around it to point at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub path: Option<PathBuf>,
+    /// Counted from 1.
+    pub line: usize,
+    /// In characters and counted from 1.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// The error at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let text = input[line_start..].lines().next().unwrap_or("");
+        Self {
+            path: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.trim_end_matches('\r').to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The error of a nom parser which was given `input`.
+    pub fn from_nom(input: &str, err: nom::Err<Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                let offset = input.len() - err.input.len();
+                Self::at(input, offset, describe(err.code))
+            }
+            nom::Err::Incomplete(_) => Self::at(input, input.len(), "the input ends too early"),
+        }
+    }
+
+    /// Names the file the input was read from in the message.
+    pub fn in_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => String::from("expected a number"),
+        ErrorKind::Char | ErrorKind::OneOf => String::from("unexpected character"),
+        ErrorKind::Tag => String::from("unexpected text"),
+        ErrorKind::MapRes => String::from("this does not fit in its type"),
+        ErrorKind::Eof => String::from("expected the end of the input"),
+        ErrorKind::CrLf => String::from("expected the end of the line"),
+        ErrorKind::Verify => String::from("this is not valid here"),
+        other => format!("{} failed", other.description()),
+    }
+}
+
+/// Like rustc, the position, then the line with a caret under the column.
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(
+                f,
+                "Fail to parse {}:{}:{}",
+                path.display(),
+                self.line,
+                self.column
+            )?,
+            None => write!(
+                f,
+                "Fail to parse line {}, column {}",
+                self.line, self.column
+            )?,
+        }
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        writeln!(f, ", {}!", self.message)?;
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(f, "{} | {}^", margin, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `solve` with the errors of `parsed` naming `path`. The parts only get the content,
+/// so whoever read it from a file wraps solving in this.
+pub fn in_input_file<T>(path: impl AsRef<Path>, solve: impl FnOnce() -> T) -> T {
+    /// Puts the path from before back, even when `solve` panics.
+    struct Restore(Option<PathBuf>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            INPUT_FILE.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(INPUT_FILE.replace(Some(path.as_ref().to_path_buf())));
+    solve()
+}
+
+/// The output of parsing a whole input, or a panic with the error in the file of
+/// `in_input_file`. The parts can only answer `None`, which would hide where it failed.
+pub fn parsed<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|err| {
+        let err = match INPUT_FILE.with_borrow(Clone::clone) {
+            Some(path) => err.in_file(path),
+            None => err,
+        };
+        panic!("{}", err)
+    })
+}
+
+/// Runs `parser` on the whole `input`, anything but whitespace left after it is an error.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser(input).map_err(|err| ParseError::from_nom(input, err))?;
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        Ok(output)
+    } else {
+        Err(ParseError::at(
+            input,
+            input.len() - rest.len(),
+            "unexpected text after the end",
+        ))
+    }
+}
+
+/// Runs `parser` on every line of `input`, each one has to be consumed whole. The error
+/// of a line is placed in `input`, so it tells the line number.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<Vec<O>, ParseError> {
+    let mut outputs = Vec::new();
+    for line in input.lines() {
+        let line_offset = line.as_ptr() as usize - input.as_ptr() as usize;
+        let error_at =
+            |offset: usize, message: String| ParseError::at(input, line_offset + offset, message);
+        match parser(line) {
+            Ok((rest, output)) if rest.trim().is_empty() => outputs.push(output),
+            Ok((rest, _)) => {
+                return Err(error_at(
+                    line.len() - rest.len(),
+                    String::from("unexpected text after the end"),
+                ))
+            }
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                return Err(error_at(line.len() - err.input.len(), describe(err.code)))
+            }
+            Err(nom::Err::Incomplete(_)) => {
+                return Err(error_at(
+                    line.len(),
+                    String::from("the line ends too early"),
+                ))
+            }
+        }
+    }
+    Ok(outputs)
+}
+
+/// Digits without a sign, parsed into any number type. Digits which do not fit in it are
+/// a failure, so a list or an `alt` around it stops there instead of backtracking.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    number(digit1, input)
+}
+
+/// Digits with an optional `-` or `+` in front, parsed into any number type, failing like
+/// `unsigned` when they do not fit.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    number(recognize(pair(opt(one_of("+-")), digit1)), input)
+}
+
+fn number<'a, T: FromStr>(
+    mut digits: impl FnMut(&'a str) -> IResult<&'a str, &'a str>,
+    input: &'a str,
+) -> IResult<&'a str, T> {
+    let (rest, digits) = digits(input)?;
+    match digits.parse() {
+        Ok(number) => Ok((rest, number)),
+        Err(_) => Err(nom::Err::Failure(Error::new(input, ErrorKind::MapRes))),
+    }
+}
+
+/// One or more of `item` separated by commas, with or without spaces after them.
+pub fn comma_list<'a, O>(
+    item: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// One or more of `line` on lines of their own.
+pub fn lines<'a, O>(
+    line: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, line)
+}
+
+/// One or more of `block` separated by blank lines, like the groups of lines of a puzzle.
+pub fn blocks<'a, O>(
+    block: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// The lines up to a blank one as a `Grid`, a character `to_cell` has no cell for and a
+/// row of another width are failures.
+pub fn char_grid<'a, T>(
+    mut to_cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    move |input: &'a str| {
+        let failure = |at: &'a str, kind: ErrorKind| nom::Err::Failure(Error::new(at, kind));
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        let mut rest = input;
+        loop {
+            let (after_row, row) = not_line_ending(rest)?;
+            if row.is_empty() {
+                break;
+            }
+            let row_width = row.chars().count();
+            if width.is_some_and(|width| width != row_width) {
+                return Err(failure(rest, ErrorKind::Verify));
+            }
+            for (index, char) in row.char_indices() {
+                let cell = to_cell(char).ok_or_else(|| failure(&row[index..], ErrorKind::Char))?;
+                cells.push(cell);
+            }
+            width = Some(row_width);
+            height += 1;
+            rest = after_row;
+            // The line ending before a blank line is left for what comes after the grid.
+            match line_ending::<&str, Error<&str>>(rest) {
+                Ok((next_row, _))
+                    if !next_row.is_empty()
+                        && line_ending::<&str, Error<&str>>(next_row).is_err() =>
+                {
+                    rest = next_row
+                }
+                _ => break,
+            }
+        }
+        match width {
+            Some(width) => Ok((rest, Grid::new(width, height, cells))),
+            None => Err(nom::Err::Error(Error::new(input, ErrorKind::Eof))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{bytes::complete::tag, sequence::preceded};
+
+    #[test]
+    fn test_numbers_and_lists() {
+        assert_eq!(Ok(("", -12)), signed::<isize>("-12"));
+        assert_eq!(Ok((",", 7u8)), unsigned::<u8>("7,"));
+        assert!(matches!(unsigned::<u8>("300"), Err(nom::Err::Failure(_))));
+        assert_eq!(
+            Ok(vec![1, -2, 3]),
+            parse_all("1,-2, 3\n", comma_list(signed::<i32>))
+        );
+        let blocks = parse_all("1\n2\n\n3\n", blocks(lines(unsigned::<u32>)));
+        assert_eq!(Ok(vec![vec![1, 2], vec![3]]), blocks);
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let to_cell = |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let (rest, grid) = char_grid(to_cell)("#.\n.#\n\nrest").unwrap();
+        assert_eq!("\n\nrest", rest);
+        assert_eq!((2, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&true), grid.get((1, 1)));
+        let err = parse_all("#.\n.x", char_grid(to_cell)).unwrap_err();
+        assert_eq!(
+            (2, 2, "unexpected character"),
+            (err.line, err.column, err.message.as_str())
+        );
+        let err = parse_all("#.\n.#.", char_grid(to_cell)).unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = |input| preceded(tag("x="), signed::<i32>)(input);
+        let err = parse_lines("x=1\nx=2\nx=a3", line).unwrap_err();
+        assert_eq!((3, 3), (err.line, err.column));
+        assert_eq!(
+            "Fail to parse input.txt:3:3, expected a number!\n  |\n3 | x=a3\n  |   ^",
+            err.in_file("input.txt").to_string()
+        );
+        let err = parse_lines("x=1 y\n", line).unwrap_err();
+        assert_eq!(
+            (1, 4, "unexpected text after the end"),
+            (err.line, err.column, err.message.as_str())
+        );
+        let err = parse_all("1,2\n3, 300", lines(comma_list(unsigned::<u8>))).unwrap_err();
+        assert_eq!(
+            (2, 4, "this does not fit in its type"),
+            (err.line, err.column, err.message.as_str())
+        );
+        let err = parse_all("12\n34", unsigned::<u32>).unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert!(err
+            .to_string()
+            .starts_with("Fail to parse line 2, column 1"));
+    }
+
+    #[test]
+    fn test_parsed_in_input_file() {
+        let parse = || parsed(parse_all("12\n34", unsigned::<u32>));
+        let message = |panic: Box<dyn std::any::Any + Send>| *panic.downcast::<String>().unwrap();
+        let err = std::panic::catch_unwind(|| in_input_file("input.txt", parse)).unwrap_err();
+        assert!(message(err).starts_with("Fail to parse input.txt:2:1"));
+        let err = std::panic::catch_unwind(parse).unwrap_err();
+        assert!(message(err).starts_with("Fail to parse line 2, column 1"));
+        assert_eq!(
+            12,
+            in_input_file("input.txt", || parsed(parse_all("12", unsigned::<u32>)))
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, IsTerminal, Write},
+    sync::{Mutex, MutexGuard, OnceLock},
+    time::{Duration, Instant},
+};
+
+/// `off` hides the bars, `on` draws them even when stderr is not a terminal. By default
+/// they are only drawn on a terminal.
+pub const PROGRESS_ENV: &str = "AOC_PROGRESS";
+/// Seconds the searches may take, counted from the first `Progress`. They are cancelled
+/// once it is over.
+pub const TIMEOUT_ENV: &str = "AOC_TIMEOUT";
+
+const BAR_WIDTH: usize = 30;
+/// Redrawing on every step would slow down the search it reports on.
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+
+struct Settings {
+    quiet: bool,
+    deadline: Option<Instant>,
+}
+
+fn settings() -> MutexGuard<'static, Settings> {
+    static SETTINGS: OnceLock<Mutex<Settings>> = OnceLock::new();
+    SETTINGS
+        .get_or_init(|| {
+            let quiet = match env::var(PROGRESS_ENV).as_deref() {
+                Ok("off") | Ok("0") => true,
+                Ok("on") | Ok("1") => false,
+                _ => !io::stderr().is_terminal(),
+            };
+            let deadline = env::var(TIMEOUT_ENV).ok().and_then(|seconds| {
+                match seconds
+                    .trim()
+                    .parse::<f64>()
+                    .map(Duration::try_from_secs_f64)
+                {
+                    Ok(Ok(timeout)) => Some(Instant::now() + timeout),
+                    _ => {
+                        crate::warn!("{} is ignored: {} is not seconds", TIMEOUT_ENV, seconds);
+                        None
+                    }
+                }
+            });
+            Mutex::new(Settings { quiet, deadline })
+        })
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
+
+/// Hides or shows the bars of every `Progress` created afterwards, over `AOC_PROGRESS`.
+pub fn set_quiet(quiet: bool) {
+    settings().quiet = quiet;
+}
+
+/// Cancels every `Progress` once `deadline` is passed, over `AOC_TIMEOUT`. `None` lets
+/// them run to the end.
+pub fn set_deadline(deadline: Option<Instant>) {
+    settings().deadline = deadline;
+}
+
+/// A search ran past its deadline, `done` out of `total` was reported by then.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cancelled {
+    pub label: String,
+    pub done: u64,
+    pub total: u64,
+    pub elapsed: Duration,
+}
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is cancelled after {}, at {} of {}!",
+            self.label,
+            format_duration(self.elapsed),
+            self.done,
+            self.total
+        )
+    }
+}
+
+impl Error for Cancelled {}
+
+/// Work done out of the total work of a long search, drawn as a bar on stderr with an
+/// estimate of the time left.
+#[derive(Debug)]
+pub struct Progress {
+    label: String,
+    total: u64,
+    done: u64,
+    started: Instant,
+    deadline: Option<Instant>,
+    quiet: bool,
+    drawn_at: Option<Instant>,
+}
+
+impl Progress {
+    /// Takes the quiet mode and the deadline set for all of them.
+    pub fn new(label: impl Into<String>, total: u64) -> Progress {
+        let settings = settings();
+        Progress {
+            label: label.into(),
+            total,
+            done: 0,
+            started: Instant::now(),
+            deadline: settings.deadline,
+            quiet: settings.quiet,
+            drawn_at: None,
+        }
+    }
+
+    /// Never draws a bar, it is still cancelled at the deadline.
+    pub fn quiet(mut self) -> Progress {
+        self.quiet = true;
+        self
+    }
+
+    /// Cancels it at `deadline`, or earlier if the shared deadline comes first.
+    pub fn with_deadline(mut self, deadline: Instant) -> Progress {
+        self.deadline = Some(self.deadline.map_or(deadline, |set| set.min(deadline)));
+        self
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Progress {
+        let deadline = self.started + timeout;
+        self.with_deadline(deadline)
+    }
+
+    pub fn done(&self) -> u64 {
+        self.done
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// For a total only known along the way.
+    pub fn set_total(&mut self, total: u64) {
+        self.total = total;
+    }
+
+    /// Reports `amount` more work done, `Err` once the deadline is passed. The search is
+    /// expected to give up then, with a `?` most of the time.
+    pub fn inc(&mut self, amount: u64) -> Result<(), Cancelled> {
+        self.done += amount;
+        let now = Instant::now();
+        if !self.quiet && self.drawn_at.is_none_or(|at| now - at >= REDRAW_EVERY) {
+            self.draw(now);
+        }
+        match self.deadline {
+            Some(deadline) if now >= deadline => Err(self.cancelled()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the deadline is passed, without reporting any work.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(self.cancelled()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Time left if the rest goes as fast as what is done, `None` before any work.
+    pub fn eta(&self) -> Option<Duration> {
+        eta(self.done, self.total, self.elapsed())
+    }
+
+    /// Leaves the bar at its last state on its own line.
+    pub fn finish(mut self) {
+        if !self.quiet {
+            self.draw(Instant::now());
+            eprintln!();
+            self.drawn_at = None;
+        }
+    }
+
+    fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            label: self.label.clone(),
+            done: self.done,
+            total: self.total,
+            elapsed: self.elapsed(),
+        }
+    }
+
+    fn draw(&mut self, now: Instant) {
+        let line = render(&self.label, self.done, self.total, now - self.started);
+        let mut stderr = io::stderr().lock();
+        // `\x1b[K` clears what is left of a longer line before it.
+        let _ = write!(stderr, "\r{}\x1b[K", line);
+        let _ = stderr.flush();
+        self.drawn_at = Some(now);
+    }
+}
+
+impl Drop for Progress {
+    /// A bar of a search given up on is cleared, so it does not end up in front of the
+    /// next line.
+    fn drop(&mut self) {
+        if self.drawn_at.is_some() {
+            let _ = write!(io::stderr(), "\r\x1b[K");
+        }
+    }
+}
+
+fn eta(done: u64, total: u64, elapsed: Duration) -> Option<Duration> {
+    if done == 0 {
+        return None;
+    }
+    let left = total.saturating_sub(done);
+    Some(elapsed.mul_f64(left as f64 / done as f64))
+}
+
+/// `label [#######.......] 25/100 25% 1.2s ETA 3.6s`
+fn render(label: &str, done: u64, total: u64, elapsed: Duration) -> String {
+    let ratio = if total == 0 {
+        1.0
+    } else {
+        (done as f64 / total as f64).min(1.0)
+    };
+    let filled = (ratio * BAR_WIDTH as f64).round() as usize;
+    let eta = match eta(done, total, elapsed) {
+        Some(eta) => format_duration(eta),
+        None => String::from("?"),
+    };
+    format!(
+        "{} [{}{}] {}/{} {:.0}% {} ETA {}",
+        label,
+        "#".repeat(filled),
+        ".".repeat(BAR_WIDTH - filled),
+        done,
+        total,
+        ratio * 100.0,
+        format_duration(elapsed),
+        eta
+    )
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    if seconds < 60.0 {
+        format!("{:.1}s", seconds)
+    } else if seconds < 3600.0 {
+        format!(
+            "{}m{:02}s",
+            duration.as_secs() / 60,
+            duration.as_secs() % 60
+        )
+    } else {
+        format!(
+            "{}h{:02}m",
+            duration.as_secs() / 3600,
+            duration.as_secs() % 3600 / 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            "day16 [########......................] 25/100 25% 1.0s ETA 3.0s",
+            render("day16", 25, 100, Duration::from_secs(1))
+        );
+        assert_eq!(
+            "day19 [..............................] 0/72 0% 0.0s ETA ?",
+            render("day19", 0, 72, Duration::ZERO)
+        );
+        assert_eq!("2m05s", format_duration(Duration::from_secs(125)));
+        assert_eq!("1h01m", format_duration(Duration::from_secs(3660)));
+    }
+
+    #[test]
+    fn test_deadline() {
+        let mut progress = Progress::new("test", 10).quiet();
+        assert_eq!(Ok(()), progress.inc(5));
+        let mut progress = progress.with_deadline(Instant::now());
+        let cancelled = progress.inc(1).unwrap_err();
+        assert_eq!((6, 10), (cancelled.done, cancelled.total));
+        assert!(progress.check().is_err());
+        assert_eq!(
+            Some(Duration::from_secs(4)),
+            eta(6, 10, Duration::from_secs(6))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded generator for making puzzle inputs, not for anything secret. It only
+/// does `u64` arithmetic, so a seed gives the same numbers on every machine.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Seeds next to each other start far apart, and 0 does not get xorshift stuck.
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        state ^= state >> 31;
+        Self {
+            state: state.max(1),
+        }
+    }
+
+    /// xorshift64*
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `0..bound`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "There is no number below 0!");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn usize_in(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "The range {}..={} is empty!", start, end);
+        start + self.offset((end - start) as u64) as usize
+    }
+
+    pub fn isize_in(&mut self, range: RangeInclusive<isize>) -> isize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "The range {}..={} is empty!", start, end);
+        start.wrapping_add(self.offset(end.abs_diff(start) as u64) as isize)
+    }
+
+    /// A number in `0..=max`.
+    fn offset(&mut self, max: u64) -> u64 {
+        let number = self.next_u64();
+        max.checked_add(1).map_or(number, |count| number % count)
+    }
+
+    /// `true` about `percent` times in a hundred.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..20).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+        assert_eq!(numbers(0), numbers(0));
+        assert_ne!(numbers(0), numbers(1));
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(2022);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let number = rng.isize_in(-3..=3);
+            assert!((-3..=3).contains(&number));
+            seen[(number + 3) as usize] = true;
+            assert!((5..=6).contains(&rng.usize_in(5..=6)));
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(usize::MAX, rng.usize_in(usize::MAX..=usize::MAX));
+        assert!((isize::MIN..=isize::MAX).contains(&rng.isize_in(isize::MIN..=isize::MAX)));
+        let mut items = (0..10).collect::<Vec<usize>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!((0..10).collect::<Vec<usize>>(), items);
+    }
+}
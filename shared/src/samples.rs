@@ -0,0 +1,171 @@
+use crate::{
+    answers::answer_string, day_dir, input::read_error, try_read_file, InputError, InputKind, Part,
+    Puzzle, Solution,
+};
+use std::{fs, path::Path};
+use toml::Value;
+
+/// Directory of a day with the examples of the puzzle, one `NAME.txt` for each.
+pub const SAMPLES_DIR: &str = "samples";
+
+/// Expected answers of the samples, next to `SAMPLES_DIR` and keyed like `[NAME]` with
+/// `part1` and `part2` in it. A part without an answer is not checked, and
+/// `params = "input"` solves the sample with the parameters of the puzzle input.
+pub const SAMPLES_FILE: &str = "samples.toml";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub content: String,
+    /// Which parameters of the `Solution` it is solved with.
+    pub params: InputKind,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Reads every sample of a day, ordered by name. A sample without an entry in
+/// `SAMPLES_FILE` is an error, since it would never be checked.
+pub fn try_read_samples(puzzle: impl Into<Puzzle>) -> Result<Vec<Sample>, InputError> {
+    try_read_samples_in(&day_dir(puzzle)?)
+}
+
+fn try_read_samples_in(day_dir: &Path) -> Result<Vec<Sample>, InputError> {
+    let samples_dir = day_dir.join(SAMPLES_DIR);
+    let path = day_dir.join(SAMPLES_FILE);
+    let bad_samples = |message: String| InputError::BadSamples {
+        path: path.clone(),
+        message,
+    };
+    let entries = match try_read_file(&path)?
+        .parse::<Value>()
+        .map_err(|err| bad_samples(err.to_string()))?
+    {
+        Value::Table(entries) => entries,
+        _ => return Err(bad_samples(String::from("it is not a table"))),
+    };
+    let mut samples = Vec::new();
+    for (name, entry) in entries.iter() {
+        let content = try_read_file(samples_dir.join(format!("{}.txt", name)))?;
+        let params = match entry.get("params") {
+            None => InputKind::Sample,
+            Some(Value::String(params)) => {
+                InputKind::try_from(params.as_str()).map_err(bad_samples)?
+            }
+            Some(other) => return Err(bad_samples(format!("{} is not an input kind", other))),
+        };
+        let mut answers = Vec::new();
+        for part in Part::all() {
+            if let Some(answer) = entry.get(format!("part{}", part)) {
+                answers.push((part, answer_string(answer).map_err(bad_samples)?));
+            }
+        }
+        samples.push(Sample {
+            name: name.clone(),
+            content,
+            params,
+            answers,
+        });
+    }
+    for name in sample_names(&samples_dir)? {
+        if !entries.contains_key(&name) {
+            return Err(bad_samples(format!("{} has no answers", name)));
+        }
+    }
+    Ok(samples)
+}
+
+fn sample_names(samples_dir: &Path) -> Result<Vec<String>, InputError> {
+    let entries = fs::read_dir(samples_dir).map_err(|source| read_error(samples_dir, source))?;
+    Ok(entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect())
+}
+
+/// Solves every sample of `files/YEAR/dayN/samples` and compares the answers with the
+/// ones in `samples.toml`, all the wrong ones are reported together.
+pub fn check_samples<S: Solution>() {
+    let samples = try_read_samples(S::METADATA.puzzle()).unwrap_or_else(|err| panic!("{}", err));
+    assert!(!samples.is_empty(), "No samples in {}!", SAMPLES_FILE);
+    let mut wrong = Vec::new();
+    for sample in samples.iter() {
+        let params = S::params(sample.params).unwrap_or_else(|err| panic!("{}", err));
+        for (part, expected) in sample.answers.iter() {
+            let answer = S::solve(*part, &sample.content, &params);
+            if answer.as_ref() != Some(expected) {
+                wrong.push(format!(
+                    "{} part{}: expected {}, got {:?}",
+                    sample.name, part, expected, answer
+                ));
+            }
+        }
+    }
+    assert!(wrong.is_empty(), "Wrong answers:\n{}", wrong.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, path::PathBuf};
+
+    #[test]
+    fn try_read_samples_from_workspace() {
+        let samples = try_read_samples(6).unwrap();
+        let names = samples
+            .iter()
+            .map(|sample| sample.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            vec!["example1", "example2", "example3", "example4", "example5"],
+            names
+        );
+        let sample = &samples[3];
+        assert_eq!("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg\n", sample.content);
+        assert_eq!(InputKind::Sample, sample.params);
+        let expected = vec![
+            (Part::One, String::from("10")),
+            (Part::Two, String::from("29")),
+        ];
+        assert_eq!(expected, sample.answers);
+    }
+
+    /// A day dir with one sample, deleted with everything in it on drop.
+    struct Day {
+        dir: PathBuf,
+    }
+
+    impl Day {
+        fn with_sample(name: &str) -> Self {
+            let dir = env::temp_dir().join(name);
+            fs::create_dir_all(dir.join(SAMPLES_DIR)).unwrap();
+            fs::write(dir.join(SAMPLES_DIR).join("example1.txt"), "1\n").unwrap();
+            Self { dir }
+        }
+
+        fn read_with_answers(&self, answers: &str) -> Result<Vec<Sample>, InputError> {
+            fs::write(self.dir.join(SAMPLES_FILE), answers).unwrap();
+            try_read_samples_in(&self.dir)
+        }
+    }
+
+    impl Drop for Day {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn try_read_samples_bad_file() {
+        let day = Day::with_sample("shared-test-samples-bad-file");
+        let not_toml = day.read_with_answers("[example1\npart1 = 1\n");
+        assert!(matches!(not_toml, Err(InputError::BadSamples { .. })));
+        let not_an_answer = day.read_with_answers("[example1]\npart1 = 1.5\n");
+        assert!(matches!(not_an_answer, Err(InputError::BadSamples { .. })));
+        let read = day.read_with_answers("[example1]\npart1 = 1\n").unwrap();
+        assert_eq!(vec![(Part::One, String::from("1"))], read[0].answers);
+    }
+}
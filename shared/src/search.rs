@@ -0,0 +1,226 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// A graph to search, given by the states one step away from each state.
+pub trait Successors {
+    type State: Clone + Eq + Hash;
+
+    /// The states one step away from `state`, with the cost of that step.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    /// A guess of the cost left from `state` to a goal, only `astar` uses it. It must never
+    /// be more than the real cost, or the path found may not be the shortest one.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S> {
+    /// From the start to the goal, both included.
+    pub states: Vec<S>,
+    pub cost: usize,
+}
+
+impl<S> Path<S> {
+    pub fn goal(&self) -> &S {
+        self.states.last().unwrap()
+    }
+}
+
+/// Every state found so far with the one it was reached from, to rebuild the path.
+struct Tree<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Tree<S> {
+    fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn state(&self, index: usize) -> &S {
+        &self.nodes[index].0
+    }
+
+    fn path(&self, index: usize, cost: usize) -> Path<S> {
+        let mut states = Vec::new();
+        let mut current = Some(index);
+        while let Some(index) = current {
+            let (state, parent) = &self.nodes[index];
+            states.push(state.clone());
+            current = *parent;
+        }
+        states.reverse();
+        Path { states, cost }
+    }
+}
+
+/// Breadth first search, every step costs 1 whatever `successors` says.
+pub fn bfs<G: Successors>(
+    graph: &G,
+    start: G::State,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    let mut tree = Tree::new();
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(start.clone());
+    queue.push_back((tree.push(start, None), 0));
+    while let Some((index, steps)) = queue.pop_front() {
+        if is_goal(tree.state(index)) {
+            return Some(tree.path(index, steps));
+        }
+        for (next, _) in graph.successors(tree.state(index)) {
+            if visited.insert(next.clone()) {
+                queue.push_back((tree.push(next, Some(index)), steps + 1));
+            }
+        }
+    }
+    None
+}
+
+pub fn dijkstra<G: Successors>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    best_first(graph, start, is_goal, |_| 0)
+}
+
+pub fn astar<G: Successors>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    best_first(graph, start, is_goal, |state| graph.heuristic(state))
+}
+
+/// The lowest cost from `start` to every state it can reach.
+pub fn distances<G: Successors>(graph: &G, start: G::State) -> HashMap<G::State, usize> {
+    let mut best = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    let mut states = vec![start.clone()];
+    best.insert(start, 0);
+    heap.push(Reverse((0, 0)));
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let state = states[index].clone();
+        if best.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        for (next, step_cost) in graph.successors(&state) {
+            let next_cost = cost + step_cost;
+            if best.get(&next).is_none_or(|best| next_cost < *best) {
+                best.insert(next.clone(), next_cost);
+                states.push(next);
+                heap.push(Reverse((next_cost, states.len() - 1)));
+            }
+        }
+    }
+    best
+}
+
+fn best_first<G: Successors>(
+    graph: &G,
+    start: G::State,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+    heuristic: impl Fn(&G::State) -> usize,
+) -> Option<Path<G::State>> {
+    let mut tree = Tree::new();
+    let mut best = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    best.insert(start.clone(), 0);
+    let priority = heuristic(&start);
+    heap.push(Reverse((priority, 0, tree.push(start, None))));
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let state = tree.state(index).clone();
+        if best.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(tree.path(index, cost));
+        }
+        for (next, step_cost) in graph.successors(&state) {
+            let next_cost = cost + step_cost;
+            if best.get(&next).is_none_or(|best| next_cost < *best) {
+                best.insert(next.clone(), next_cost);
+                let priority = next_cost + heuristic(&next);
+                heap.push(Reverse((priority, next_cost, tree.push(next, Some(index)))));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1-> b -1-> d, a -5-> c -1-> d, and b -3-> c
+    struct Weighted;
+
+    impl Successors for Weighted {
+        type State = char;
+
+        fn successors(&self, state: &char) -> Vec<(char, usize)> {
+            match state {
+                'a' => vec![('b', 1), ('c', 5)],
+                'b' => vec![('d', 1), ('c', 3)],
+                'c' => vec![('d', 1)],
+                _ => vec![],
+            }
+        }
+    }
+
+    /// Walking on a line of numbers, the goal is 10.
+    struct Line;
+
+    impl Successors for Line {
+        type State = isize;
+
+        fn successors(&self, state: &isize) -> Vec<(isize, usize)> {
+            vec![(state - 1, 1), (state + 1, 1)]
+        }
+
+        fn heuristic(&self, state: &isize) -> usize {
+            (10 - state).unsigned_abs()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(&Weighted, 'a', |state| *state == 'd').unwrap();
+        assert_eq!(vec!['a', 'b', 'd'], path.states);
+        assert_eq!(2, path.cost);
+        assert_eq!(None, bfs(&Weighted, 'd', |state| *state == 'a'));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(&Weighted, 'a', |state| *state == 'c').unwrap();
+        assert_eq!(vec!['a', 'b', 'c'], path.states);
+        assert_eq!(4, path.cost);
+    }
+
+    #[test]
+    fn test_astar() {
+        let path = astar(&Line, 0, |state| *state == 10).unwrap();
+        assert_eq!(10, path.cost);
+        assert_eq!(&10, path.goal());
+        assert_eq!((0..=10).collect::<Vec<isize>>(), path.states);
+    }
+
+    #[test]
+    fn test_distances() {
+        let distances = distances(&Weighted, 'a');
+        let expected = HashMap::from([('a', 0), ('b', 1), ('c', 4), ('d', 2)]);
+        assert_eq!(expected, distances);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{random::Rng, try_read_params, InputError};
+use serde::de::DeserializeOwned;
+use std::fmt::Display;
+
+/// The year a bare day number stands for, the one this workspace started with.
+pub const DEFAULT_YEAR: usize = 2022;
+
+/// A puzzle of a year, anything taking `impl Into<Puzzle>` also takes a day number of
+/// `DEFAULT_YEAR`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Puzzle {
+    pub year: usize,
+    pub day: usize,
+}
+
+impl Puzzle {
+    pub fn new(year: usize, day: usize) -> Self {
+        Self { year, day }
+    }
+
+    /// Reads the name of a day crate, like `day5`, `aoc2021-day5` or its lib name `aoc2021_day5`.
+    pub fn of_crate(name: &str) -> Option<Self> {
+        match name.strip_prefix("aoc") {
+            Some(name) => {
+                let (year, day) = name.split_once("-day").or(name.split_once("_day"))?;
+                Some(Self::new(year.parse().ok()?, day.parse().ok()?))
+            }
+            None => Some(Self::from(name.strip_prefix("day")?.parse::<usize>().ok()?)),
+        }
+    }
+}
+
+impl From<usize> for Puzzle {
+    fn from(day: usize) -> Self {
+        Self::new(DEFAULT_YEAR, day)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} of {}", self.day, self.year)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Metadata {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+}
+
+impl Metadata {
+    pub fn puzzle(&self) -> Puzzle {
+        Puzzle::new(self.year, self.day)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputKind {
+    Sample,
+    Input,
+}
+
+impl InputKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Sample => "sample",
+            InputKind::Input => "input",
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            InputKind::Sample => "sample.txt",
+            InputKind::Input => "input.txt",
+        }
+    }
+}
+
+impl TryFrom<&str> for InputKind {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        match name {
+            "sample" => Ok(InputKind::Sample),
+            "input" => Ok(InputKind::Input),
+            _ => Err(format!(
+                "{} is not an input kind, try sample or input!",
+                name
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> Vec<Part> {
+        vec![Part::One, Part::Two]
+    }
+}
+
+impl TryFrom<usize> for Part {
+    type Error = String;
+
+    fn try_from(number: usize) -> Result<Self, Self::Error> {
+        match number {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Part {} does not exist, try 1 or 2!", number)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day of the puzzle, implemented by a unit struct in every day crate.
+///
+/// `Params` holds the extra arguments some days need, read from the `[sample]` or
+/// `[input]` table of `files/YEAR/dayN/params.toml`. Its `Default` stands in when there
+/// is none, days without any use `()`.
+pub trait Solution {
+    const METADATA: Metadata;
+    type Params: Default + DeserializeOwned;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn part1(content: &str, params: &Self::Params) -> Option<Self::Answer1>;
+
+    fn part2(content: &str, params: &Self::Params) -> Option<Self::Answer2>;
+
+    fn params(input_kind: InputKind) -> Result<Self::Params, InputError> {
+        try_read_params(Self::METADATA.puzzle(), input_kind)
+    }
+
+    /// Only parses `content` into what the parts start from, so the bench can time parsing
+    /// apart from solving. `None` for the days which parse along the way.
+    fn parse(_content: &str, _params: &Self::Params) -> Option<impl Sized> {
+        None::<()>
+    }
+
+    /// A random input the size of `size`, in whatever unit fits the day, for stress and
+    /// scaling tests. `None` for the days which have no generator.
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+
+    fn solve(part: Part, content: &str, params: &Self::Params) -> Option<String> {
+        match part {
+            Part::One => Self::part1(content, params).map(|answer| answer.to_string()),
+            Part::Two => Self::part2(content, params).map(|answer| answer.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_from_day() {
+        assert_eq!(Puzzle::new(DEFAULT_YEAR, 5), Puzzle::from(5));
+        assert!(Puzzle::new(2021, 25) < Puzzle::new(2022, 1));
+    }
+
+    #[test]
+    fn test_part_try_from() {
+        assert_eq!(Ok(Part::Two), Part::try_from(2));
+        assert!(Part::try_from(3).is_err());
+    }
+}